@@ -0,0 +1,63 @@
+//! Generates the list of solutions that the `registry` binary links.
+//! Every `src/bin/YYYY/NN.rs` is included as a module, so newly scaffolded days are picked up on the next build.
+use std::{env, fs, path::Path, path::PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bin");
+
+    let mut solutions: Vec<(u16, u8)> = vec![];
+
+    for year in read_numbered(&bin_dir, 4) {
+        let year_dir = bin_dir.join(year.to_string());
+        for day in read_numbered(&year_dir, 2) {
+            solutions.push((year, day.try_into().unwrap()));
+        }
+    }
+
+    solutions.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, day) in solutions {
+        let path = bin_dir.join(year.to_string()).join(format!("{day:02}.rs"));
+        // solutions are linted as their own binaries already.
+        modules.push_str(&format!(
+            "#[allow(warnings, clippy::all, clippy::pedantic)]\n#[path = {path:?}]\nmod day_{year}_{day:02};\n"
+        ));
+        entries.push_str(&format!(
+            "    (advent_of_code::year!({year}), advent_of_code::day!({day}), day_{year}_{day:02}::main),\n"
+        ));
+    }
+
+    let registry = format!(
+        "{modules}\nconst SOLUTIONS: &[(advent_of_code::template::Year, advent_of_code::template::Day, fn())] = &[\n{entries}];\n"
+    );
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(out_path, registry).unwrap();
+}
+
+/// Numbers of the entries in `dir` whose name (without extension) has the given amount of digits,
+/// i.e. year directories and day files.
+fn read_numbered(dir: &Path, digits: usize) -> Vec<u16> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".rs").unwrap_or(&name);
+            if stem.len() == digits && stem.bytes().all(|b| b.is_ascii_digit()) {
+                stem.parse().ok()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2024, 1);
 
 fn parse_input(input: &str) -> (Vec<u32>, Vec<u32>) {
     input
@@ -62,13 +62,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(31));
     }
 }
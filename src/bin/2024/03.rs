@@ -1,5 +1,5 @@
 use regex::{Captures, Regex};
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 fn get_multiplied_value_from_capture(c: Captures<'_>) -> u32 {
     let left = &c.get(1).unwrap().as_str().parse::<u32>().unwrap();
@@ -47,13 +47,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(161));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(48));
     }
 }
@@ -3,7 +3,7 @@ use indoc::indoc;
 use itertools::{any, Itertools};
 use std::collections::HashMap;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2024, 4);
 
 fn parse(input: &str) -> Grid<char> {
     // let locations: HashMap<Location, char> = input
@@ -153,13 +153,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(9));
     }
 }
@@ -5,7 +5,7 @@ use std::str::Lines;
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2024, 5);
 
 type NumbersOrderedAfter = HashSet<u32>;
 
@@ -120,13 +120,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(123));
     }
 }
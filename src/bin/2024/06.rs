@@ -4,10 +4,10 @@ use std::{
     fmt::Display,
 };
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2024, 6, parse = parse);
 
 #[derive(PartialEq, Clone, Copy, Debug, Eq, Hash, PartialOrd, Ord)]
-enum Entity {
+pub enum Entity {
     Ground,
     Obstacle,
     Guard(Direction),
@@ -116,10 +116,8 @@ fn move_guard(
     true
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let grid = parse(input);
-
-    let (mut guard_location, mut guard_direction) = find_guard_starting_location(&grid);
+pub fn part_one(grid: &Grid<Entity>) -> Option<u32> {
+    let (mut guard_location, mut guard_direction) = find_guard_starting_location(grid);
 
     let mut visited_locations: HashSet<Location> = HashSet::new();
     visited_locations.insert(guard_location);
@@ -129,7 +127,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         //     grid.display(Some(&visited_locations));
         // }
 
-        if !move_guard(&grid, &mut guard_location, &mut guard_direction) {
+        if !move_guard(grid, &mut guard_location, &mut guard_direction) {
             break;
         }
 
@@ -139,17 +137,15 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(visited_locations.len() as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let grid = parse(input);
-
-    let (mut guard_location, mut guard_direction) = find_guard_starting_location(&grid);
+pub fn part_two(grid: &Grid<Entity>) -> Option<u32> {
+    let (mut guard_location, mut guard_direction) = find_guard_starting_location(grid);
     let original_guard_location = guard_location.clone();
 
     let mut visited_locations: HashSet<Location> = HashSet::new();
     visited_locations.insert(guard_location);
 
     loop {
-        if !move_guard(&grid, &mut guard_location, &mut guard_direction) {
+        if !move_guard(grid, &mut guard_location, &mut guard_direction) {
             break;
         }
 
@@ -202,13 +198,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
         assert_eq!(result, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
         assert_eq!(result, Some(6));
     }
 }
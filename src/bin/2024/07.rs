@@ -2,7 +2,7 @@ use std::iter::repeat;
 
 use itertools::Itertools;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2024, 7);
 
 #[derive(PartialEq, Clone, Copy, Debug, Eq, Hash, PartialOrd, Ord)]
 enum Operator {
@@ -102,13 +102,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11387));
     }
 }
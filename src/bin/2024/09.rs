@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2024, 9);
 
 fn construct_disk(input: &str) -> (Vec<String>, usize) {
     let mut iterator = input
@@ -141,20 +141,20 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1928));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2858));
     }
 
     #[test]
     fn test_part_two_test1() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(3317));
     }
@@ -162,7 +162,7 @@ mod tests {
     #[test]
     fn test_part_two_test2() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(1));
     }
@@ -170,7 +170,7 @@ mod tests {
     #[test]
     fn test_part_two_test3() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", YEAR, DAY, 3,
         ));
         assert_eq!(result, Some(4));
     }
@@ -178,7 +178,7 @@ mod tests {
     #[test]
     fn test_part_two_test4() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
+            "examples", YEAR, DAY, 4,
         ));
         assert_eq!(result, Some(813));
     }
@@ -186,7 +186,7 @@ mod tests {
     #[test]
     fn test_part_two_test5() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 5,
+            "examples", YEAR, DAY, 5,
         ));
         assert_eq!(result, Some(4));
     }
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use advent_of_code::{Grid, Location};
 use itertools::Itertools;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2024, 10);
 
 fn parse(input: &str) -> Grid<u32> {
     Grid::parse(input, |c| match c {
@@ -127,14 +127,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(1));
     }
 
     #[test]
     fn test_part_one_simple() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(1));
     }
@@ -142,7 +142,7 @@ mod tests {
     #[test]
     fn test_part_one_medium() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(36));
     }
@@ -150,21 +150,21 @@ mod tests {
     #[test]
     fn test_part_one_dots() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", YEAR, DAY, 3,
         ));
         assert_eq!(result, Some(4));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(16));
     }
 
     #[test]
     fn test_part_two_dots() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
+            "examples", YEAR, DAY, 4,
         ));
         assert_eq!(result, Some(13));
     }
@@ -7,7 +7,7 @@ use std::{
 use advent_of_code::{Direction, Grid, Location};
 use itertools::{min, Itertools};
 
-advent_of_code::solution!(12);
+advent_of_code::solution!(2024, 12);
 
 fn fill_surroundings(
     grid: &Grid<char>,
@@ -168,20 +168,20 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(140));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(80));
     }
 
     #[test]
     fn test_part_two_simple() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(236));
     }
@@ -189,7 +189,7 @@ mod tests {
     #[test]
     fn test_part_two_hard() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(368));
     }
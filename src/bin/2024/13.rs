@@ -2,7 +2,7 @@ use advent_of_code::Location;
 use itertools::Itertools;
 use regex::Regex;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(2024, 13);
 
 #[derive(PartialEq, Clone, Copy, Debug, Eq, Hash, PartialOrd, Ord)]
 struct Button {
@@ -93,13 +93,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -1,6 +1,6 @@
 use advent_of_code::{Direction, Grid, Location};
 use itertools::Itertools;
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, 15);
 
 fn parse(input: &str) -> Grid<char> {
     Grid::parse(input, |c| match c {
@@ -131,14 +131,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(10092));
     }
 
     #[test]
     fn test_part_small() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, Some(2028));
     }
@@ -146,14 +146,14 @@ mod tests {
     #[test]
     fn test_part_single_box() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(104));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
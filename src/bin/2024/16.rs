@@ -4,7 +4,7 @@ use advent_of_code::{Direction, Grid, Location};
 use itertools::Itertools;
 use pathfinding::prelude::{astar_bag, dijkstra};
 
-advent_of_code::solution!(16);
+advent_of_code::solution!(2024, 16);
 
 fn remove_entry(grid: &mut Grid<char>, _char: char) -> (Location, char) {
     let removed = grid
@@ -159,21 +159,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(7036));
     }
 
     #[test]
     fn test_part_one_simple() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", YEAR, DAY, 1,
         ));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -3,7 +3,7 @@ use std::{fmt::Display, ops::Rem};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2024, 17, variants = [2 => part_two_search]);
 
 #[derive(PartialEq, Clone, Debug, Eq, Hash, PartialOrd, Ord)]
 struct StrangeDevice {
@@ -203,13 +203,42 @@ pub fn part_two(input: &str) -> Option<u64> {
     result
 }
 
+/// The program shifts register a by 3 bits per output, so a can be built up 3 bits at a time,
+/// matching the program from its last instruction backwards.
+pub fn part_two_search(input: &str) -> Option<u64> {
+    let strange_device = parse(input);
+    let program = strange_device
+        .program
+        .iter()
+        .map(|&op| op as u64)
+        .collect_vec();
+
+    let mut candidates = vec![0u64];
+
+    for length in 1..=program.len() {
+        let expected = &program[program.len() - length..];
+        candidates = candidates
+            .into_iter()
+            .flat_map(|a| (0..8).map(move |bits| (a << 3) | bits))
+            .filter(|&a| {
+                let mut copied_strange_device = strange_device.clone();
+                copied_strange_device.register_a = a;
+                copied_strange_device.complete();
+                copied_strange_device.output == expected
+            })
+            .collect();
+    }
+
+    candidates.into_iter().min()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_string()));
     }
 
@@ -300,7 +329,15 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
+        ));
+        assert_eq!(result, Some(117440));
+    }
+
+    #[test]
+    fn test_part_two_search() {
+        let result = part_two_search(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(117440));
     }
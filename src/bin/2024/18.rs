@@ -4,7 +4,7 @@ use advent_of_code::{Grid, Location};
 use itertools::Itertools;
 use pathfinding::prelude::dijkstra;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2024, 18);
 
 fn find_solution_for_bytes(
     locations: &Vec<(Location, char)>,
@@ -104,13 +104,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -2,7 +2,7 @@ use itertools::Itertools;
 use lazy_static::lazy_static;
 use std::{cmp::Reverse, collections::HashMap, sync::Mutex};
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2024, 19);
 
 lazy_static! {
     static ref CACHED_PATTERNS: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
@@ -68,13 +68,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
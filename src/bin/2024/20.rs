@@ -6,7 +6,7 @@ use advent_of_code::{Grid, Location};
 use itertools::Itertools;
 use pathfinding::prelude::{astar, astar_bag, astar_bag_collect, dijkstra_all};
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2024, 20);
 
 fn is_wall(grid: &Grid<char>, x: u32, y: u32) -> bool {
     let Some(item) = grid.get(x, y) else {
@@ -187,13 +187,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(0));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -1,7 +1,7 @@
 use itertools::{self, Itertools};
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(23);
+advent_of_code::solution!(2024, 23, parse = parse_connections);
 
 pub fn parse_connections(input: &str) -> HashMap<&str, HashSet<&str>> {
     input
@@ -64,21 +64,18 @@ fn check_inter_connected<'a>(
     })
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let connections: HashMap<&str, HashSet<&str>> = parse_connections(input);
-    let combinations: Vec<Vec<&&str>> = get_combination(&connections, 3);
+pub fn part_one(connections: &HashMap<&str, HashSet<&str>>) -> Option<u32> {
+    let combinations: Vec<Vec<&&str>> = get_combination(connections, 3);
 
     let inter_connected_computers = combinations
         .iter()
-        .filter(|combination| check_inter_connected(&connections, combination, true))
+        .filter(|combination| check_inter_connected(connections, combination, true))
         .count();
 
     Some(inter_connected_computers.try_into().unwrap())
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let connections: HashMap<&str, HashSet<&str>> = parse_connections(input);
-
+pub fn part_two(connections: &HashMap<&str, HashSet<&str>>) -> Option<String> {
     let max_connections = connections
         .iter()
         .map(|(computer, other_computers)| {
@@ -115,13 +112,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse_connections(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse_connections(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
         assert_eq!(result, Some("co,de,ka,ta".to_string()));
     }
 }
@@ -0,0 +1,7 @@
+// Links every scaffolded solution into one binary, so that `cargo all` and `cargo time` run them in a single process.
+// The list of solutions is generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+fn main() {
+    advent_of_code::template::registry::run(SOLUTIONS);
+}
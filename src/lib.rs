@@ -5,6 +5,11 @@ use std::fmt::{Debug, Display};
 
 pub mod template;
 
+// NOTE: defined in the library rather than per solution, the `registry` binary links all of them.
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
 // Use this file to add helper functions and additional modules.
 
 #[derive(PartialEq, Clone, Copy, Debug, Eq, Hash, PartialOrd, Ord)]
@@ -1,81 +1,215 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, doctor, download, examples, read, report, scaffold, solve, time,
+};
+use advent_of_code::template::{legacy_data, Year};
 use args::{parse, AppArguments};
+use std::path::Path;
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::{commands::today, SystemClock};
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        runner::BenchConfig, Day, InputSource, ReportFormat, ReportSort, Timeouts, Year,
+        DEFAULT_COMPARE_THRESHOLD,
+    };
+    use std::{path::PathBuf, process, time::Duration};
 
     pub enum AppArguments {
         Download {
             day: Day,
+            force: bool,
         },
+        Doctor,
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+            blocks: Option<String>,
+            overwrite: bool,
+        },
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            refresh_tests: bool,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            accept: Option<u8>,
+            compare: bool,
+            input: InputSource,
         },
         All {
             release: bool,
+            jobs: usize,
+            timeouts: Timeouts,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            memory: bool,
+            compare: Option<f64>,
+            bench_config: BenchConfig,
+            timeouts: Timeouts,
+        },
+        TimeHistory {
+            day: Day,
+        },
+        Report {
+            days: Vec<Day>,
+            format: ReportFormat,
+            sort: ReportSort,
+            output: Option<PathBuf>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the year it applies to, i.e. `--year` or `AOC_YEAR` if not passed.
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year: Year = match args.opt_value_from_str("--year")? {
+            Some(year) => year,
+            None => Year::from_env().ok_or(
+                "no year selected, pass `--year` or set `AOC_YEAR` in .cargo/config.toml.",
+            )?,
+        };
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeouts: parse_timeouts(&mut args)?,
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                day: check_day(year, args.free_from_str()?)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let memory = args.contains("--memory");
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?;
+
+                let env_config = BenchConfig::from_env();
+                let bench_config = BenchConfig {
+                    duration: args
+                        .opt_value_from_str("--bench-time")?
+                        .map_or(env_config.duration, Duration::from_millis),
+                    min_samples: args
+                        .opt_value_from_str("--min-samples")?
+                        .unwrap_or(env_config.min_samples),
+                    max_samples: args
+                        .opt_value_from_str("--max-samples")?
+                        .unwrap_or(env_config.max_samples),
+                };
+
+                let timeouts = parse_timeouts(&mut args)?;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| check_day(year, day))
+                        .transpose()?,
                     store,
+                    memory,
+                    compare: compare.then(|| threshold.unwrap_or(DEFAULT_COMPARE_THRESHOLD)),
+                    bench_config,
+                    timeouts,
+                }
+            }
+            Some("report") => {
+                let format = args
+                    .opt_value_from_str("--format")?
+                    .unwrap_or(ReportFormat::Markdown);
+                let sort = args.opt_value_from_str("--sort")?.unwrap_or_default();
+                let output = args.opt_value_from_str("--output")?;
+
+                let mut days = vec![];
+                while let Some(day) = args.opt_free_from_str()? {
+                    days.push(check_day(year, day)?);
+                }
+
+                AppArguments::Report {
+                    days,
+                    format,
+                    sort,
+                    output,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                day: check_day(year, args.free_from_str()?)?,
+                force: args.contains("--force"),
             },
+            Some("doctor") => AppArguments::Doctor,
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                day: check_day(year, args.free_from_str()?)?,
+            },
+            Some("examples") => AppArguments::Examples {
+                blocks: args.opt_value_from_str("--blocks")?,
+                overwrite: args.contains("--overwrite"),
+                day: check_day(year, args.free_from_str()?)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                day: check_day(year, args.free_from_str()?)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                refresh_tests: args.contains("--refresh-tests"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let accept = args.opt_value_from_str("--accept")?;
+                let dhat = args.contains("--dhat");
+                let compare = args.contains("--compare");
+                let input_path: Option<String> = args.opt_value_from_str("--input")?;
+                let example = args.contains("--example");
+                let day = check_day(year, args.free_from_str()?)?;
+
+                // NOTE: the example number is optional, so it is parsed as a free argument after the day.
+                let input = match (input_path, example) {
+                    (Some(_), true) => {
+                        eprintln!("`--input` and `--example` can not be combined.");
+                        process::exit(1);
+                    }
+                    (Some(path), false) if path == "-" => InputSource::Stdin,
+                    (Some(path), false) => InputSource::File(path.into()),
+                    (None, true) => InputSource::Examples(args.opt_free_from_str()?),
+                    (None, false) => InputSource::Inputs,
+                };
+
+                if compare && (dhat || submit.is_some() || accept.is_some()) {
+                    eprintln!(
+                        "`--compare` can not be combined with `--dhat`, `--submit` or `--accept`."
+                    );
+                    process::exit(1);
+                }
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    dhat,
+                    submit,
+                    accept,
+                    compare,
+                    input,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -91,29 +225,112 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    /// Rejects days that are past the end of the event, e.g. day 13 of a twelve day event.
+    fn check_day(year: Year, day: Day) -> Result<Day, String> {
+        if year.has_day(day) {
+            Ok(day)
+        } else {
+            Err(format!(
+                "the {year} event only has {} days.",
+                year.last_day()
+            ))
+        }
+    }
+
+    /// Parses `--timeout <secs>` (per day) and `--part-timeout <secs>` (per part).
+    fn parse_timeouts(args: &mut pico_args::Arguments) -> Result<Timeouts, pico_args::Error> {
+        Ok(Timeouts {
+            day: args.opt_value_from_fn("--timeout", Timeouts::parse_secs)?,
+            part: args.opt_value_from_fn("--part-timeout", Timeouts::parse_secs)?,
+        })
+    }
+}
+
+/// Moves data of the layout before years were supported to `data/$AOC_YEAR`, see [`legacy_data`].
+fn migrate_legacy_data() {
+    let Some(year) = Year::from_env() else {
+        return;
+    };
+
+    match legacy_data::migrate(Path::new("data"), year) {
+        Ok(moved) if !moved.is_empty() => {
+            println!(
+                "Moved {} files of the old `data/` layout to \"data/{year}\".",
+                moved.len()
+            );
+        }
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to move data to \"data/{year}\": {e}");
+            std::process::exit(1);
+        }
     }
 }
 
 fn main() {
+    migrate_legacy_data();
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                release,
+                jobs,
+                timeouts,
+            } => all::handle(year, release, jobs, &timeouts),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                memory,
+                compare,
+                bench_config,
+                timeouts,
+            } => time::handle(
+                year,
+                day,
+                all,
+                store,
+                memory,
+                compare,
+                &bench_config,
+                &timeouts,
+            ),
+            AppArguments::TimeHistory { day } => time::history(year, day),
+            AppArguments::Report {
+                days,
+                format,
+                sort,
+                output,
+            } => report::handle(year, &days, format, sort, output),
+            AppArguments::Download { day, force } => download::handle(year, day, force),
+            AppArguments::Doctor => doctor::handle(year),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Examples {
+                day,
+                blocks,
+                overwrite,
+            } => examples::handle(year, day, blocks.as_deref(), overwrite),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                refresh_tests,
             } => {
-                scaffold::handle(day, overwrite);
+                if refresh_tests {
+                    scaffold::refresh_tests(year, day);
+                    return;
+                }
+                scaffold::handle(year, day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(year, day, false);
+                    scaffold::refresh_tests(year, day);
                 }
             }
             AppArguments::Solve {
@@ -121,24 +338,12 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                accept,
+                compare,
+                input,
+            } => solve::handle(year, day, release, dhat, submit, accept, compare, &input),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(&SystemClock, wait),
         },
     };
 }
@@ -0,0 +1,154 @@
+/// Registry of accepted answers, stored per day in `data/YYYY/answers/NN.json`.
+/// Used to mark results as correct or incorrect without re-submitting them.
+use std::{collections::HashMap, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{create_data_dir, get_data_dir, Day, Year};
+
+/// The known answers of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Outcome of comparing a result against a known answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+}
+
+impl Answers {
+    /// Reads the answers of a day. If not present or invalid, returns empty answers.
+    pub fn read(year: Year, day: Day) -> Self {
+        fs::read_to_string(get_answers_path(year, day))
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Writes the answers of a day, creating `data/YYYY/answers` if necessary.
+    pub fn store(&self, year: Year, day: Day) -> Result<(), io::Error> {
+        create_data_dir(year)?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_answers_path(year, day))?;
+        json.format_to(&mut file)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            2 => self.part_2 = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// Compares a result with the known answer of a part. Returns `None` if no answer is known.
+    pub fn check(&self, part: u8, result: &str) -> Option<Verdict> {
+        self.get(part).map(|answer| {
+            if answer == result {
+                Verdict::Correct
+            } else {
+                Verdict::Incorrect
+            }
+        })
+    }
+}
+
+fn get_answers_path(year: Year, day: Day) -> PathBuf {
+    get_data_dir(year)
+        .join("answers")
+        .join(format!("{day}.json"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let part = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        Ok(Answers {
+            part_1: part("part_1"),
+            part_2: part("part_2"),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers {
+            part_1: Some("41".into()),
+            part_2: None,
+        };
+        assert_eq!(answers.check(1, "41"), Some(Verdict::Correct));
+        assert_eq!(answers.check(1, "42"), Some(Verdict::Incorrect));
+        assert_eq!(answers.check(2, "6"), None);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = Answers {
+            part_1: Some("41".into()),
+            part_2: Some("co,de\nka,ta".into()),
+        };
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn handles_partial_answers() {
+        let json = r#"{ "part_1": "41" }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.part_1, Some("41".into()));
+        assert_eq!(answers.part_2, None);
+    }
+}
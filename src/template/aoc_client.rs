@@ -0,0 +1,507 @@
+/// Client for the Advent of Code website. Downloads inputs and puzzles and submits answers on behalf of the user.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{
+    create_data_dir, get_data_dir, html,
+    input_checksums::{InputChecksums, InputConflict},
+    Day, Year,
+};
+
+/// Identifies the client to the Advent of Code servers, as their automation guidelines ask for.
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/vincevannoort/advent-of-code-2024)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// Neither the environment variable nor the session file hold a session token.
+    SessionNotFound,
+    /// The session file can be read by other users.
+    InsecureSessionFile(PathBuf),
+    /// The server rejected the session token.
+    Unauthorized,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotFound,
+    BadStatus(u16),
+    /// The request did not reach the server or its response could not be read.
+    Transport(String),
+    /// The response did not contain the expected content.
+    UnexpectedResponse,
+    /// Writing the downloaded input would replace a different one, see [`InputConflict`].
+    InputConflict(InputConflict, PathBuf),
+    Io(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session token found. Set {} or store it in {}.",
+                AocClient::SESSION_ENV,
+                get_session_path().map_or("~/.adventofcode.session".into(), |p| p
+                    .display()
+                    .to_string())
+            ),
+            AocClientError::InsecureSessionFile(path) => write!(
+                f,
+                "{} can be read by other users, restrict it with `chmod 600`.",
+                path.display()
+            ),
+            AocClientError::Unauthorized => {
+                write!(f, "the session token was rejected, it may have expired.")
+            }
+            AocClientError::NotFound => write!(f, "the puzzle is not available (yet)."),
+            AocClientError::BadStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "the request failed: {e}"),
+            AocClientError::UnexpectedResponse => {
+                write!(f, "the response did not contain the expected content.")
+            }
+            AocClientError::InputConflict(InputConflict::Recorded, path) => write!(
+                f,
+                "the downloaded input differs from the one recorded for \"{}\", pass `--force` to replace it.",
+                path.display()
+            ),
+            AocClientError::InputConflict(InputConflict::Local, path) => write!(
+                f,
+                "\"{}\" differs from the downloaded input, pass `--force` to overwrite it.",
+                path.display()
+            ),
+            AocClientError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::Io(e)
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub const BASE_URL_ENV: &'static str = "AOC_BASE_URL";
+    pub const SESSION_ENV: &'static str = "ADVENT_OF_CODE_SESSION";
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            // NOTE: the server redirects requests it does not accept, following them would hide the reason.
+            .redirects(0)
+            .build();
+
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    /// Creates a client with the session token of the user and the base URL from `AOC_BASE_URL`, if set.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url =
+            env::var(Self::BASE_URL_ENV).unwrap_or_else(|_| Self::DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    pub fn input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        read_response(self.get(year, day, "/input").call())
+    }
+
+    /// The puzzle description as markdown. Once the first part is solved, it includes the second part.
+    pub fn puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let page = read_response(self.get(year, day, "").call())?;
+        let articles = html::extract_articles(&page);
+
+        if articles.is_empty() {
+            return Err(AocClientError::UnexpectedResponse);
+        }
+
+        Ok(articles
+            .iter()
+            .map(|article| html::to_markdown(article))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Submits an answer and returns the server's verdict as markdown.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let page = read_response(
+            self.request("POST", year, day, "/answer")
+                .send_form(&[("level", &part.to_string()), ("answer", answer)]),
+        )?;
+
+        html::extract_articles(&page)
+            .first()
+            .map(|article| html::to_markdown(article))
+            .ok_or(AocClientError::UnexpectedResponse)
+    }
+
+    fn get(&self, year: Year, day: Day, path: &str) -> ureq::Request {
+        self.request("GET", year, day, path)
+    }
+
+    /// A request for a page of a day, e.g. `/input`, authenticated with the session token.
+    fn request(&self, method: &str, year: Year, day: Day, path: &str) -> ureq::Request {
+        let url = format!("{}/{year}/day/{}{path}", self.base_url, day.into_inner());
+        self.agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn read_response(result: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, _)) => return Err(status_error(status)),
+        Err(ureq::Error::Transport(e)) => return Err(AocClientError::Transport(e.to_string())),
+    };
+
+    // redirects are not followed, they are returned as regular responses.
+    if response.status() != 200 {
+        return Err(status_error(response.status()));
+    }
+
+    response
+        .into_string()
+        .map_err(|e| AocClientError::Transport(e.to_string()))
+}
+
+fn status_error(status: u16) -> AocClientError {
+    match status {
+        // NOTE: the server answers requests with a missing or expired session with a 400 or a redirect to the login.
+        302 | 400 | 401 | 403 => AocClientError::Unauthorized,
+        404 => AocClientError::NotFound,
+        status => AocClientError::BadStatus(status),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The session file that aoc-cli uses as well, so existing setups keep working.
+fn get_session_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".adventofcode.session"))
+}
+
+/// Reads the session token from `ADVENT_OF_CODE_SESSION` or, if unset, from `~/.adventofcode.session`.
+fn read_session() -> Result<String, AocClientError> {
+    if let Some(session) = env::var(AocClient::SESSION_ENV)
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+    {
+        return Ok(session);
+    }
+
+    let path = get_session_path().ok_or(AocClientError::SessionNotFound)?;
+
+    let session = match fs::read_to_string(&path) {
+        Ok(session) => session,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Err(AocClientError::SessionNotFound)
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    check_permissions(&path)?;
+
+    Some(session.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or(AocClientError::SessionNotFound)
+}
+
+/// The session token grants access to the account, so the file must not be readable by anyone else.
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Result<(), AocClientError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if is_private(fs::metadata(path)?.permissions().mode()) {
+        Ok(())
+    } else {
+        Err(AocClientError::InsecureSessionFile(path.to_path_buf()))
+    }
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Result<(), AocClientError> {
+    Ok(())
+}
+
+#[cfg_attr(not(unix), allow(dead_code))]
+fn is_private(mode: u32) -> bool {
+    mode & 0o077 == 0
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn get_input_path(year: Year, day: Day) -> PathBuf {
+    get_data_dir(year).join("inputs").join(format!("{day}.txt"))
+}
+
+pub fn get_puzzle_path(year: Year, day: Day) -> PathBuf {
+    get_data_dir(year).join("puzzles").join(format!("{day}.md"))
+}
+
+/// Downloads the input and the puzzle description of a day and records the checksum of the input.
+/// Unless `force` is set, an input that differs from the recorded or existing one is not written.
+pub fn download(year: Year, day: Day, force: bool) -> Result<(), AocClientError> {
+    download_with(&AocClient::from_env()?, year, day, force)
+}
+
+pub(crate) fn download_with(
+    client: &AocClient,
+    year: Year,
+    day: Day,
+    force: bool,
+) -> Result<(), AocClientError> {
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    let input = client.input(year, day)?;
+    let mut checksums = InputChecksums::read(year);
+
+    if !force {
+        let local = fs::read_to_string(&input_path).ok();
+        checksums
+            .check_download(day, local.as_deref(), &input)
+            .map_err(|conflict| AocClientError::InputConflict(conflict, input_path.clone()))?;
+    }
+
+    let puzzle = client.puzzle(year, day)?;
+
+    create_data_dir(year)?;
+    fs::write(&input_path, &input)?;
+    fs::write(&puzzle_path, puzzle)?;
+
+    // NOTE: the checksum is recorded last, so a failed download does not leave a recorded input behind.
+    checksums.record(day, &input);
+    checksums.store(year)?;
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Downloads the puzzle description of a day and prints it.
+pub fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let puzzle = client.puzzle(year, day)?;
+
+    create_data_dir(year)?;
+    fs::write(get_puzzle_path(year, day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Submits an answer and prints the server's response, which is returned as well.
+pub fn submit(year: Year, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+    let response = AocClient::from_env()?.submit(year, day, part, answer)?;
+    print!("{response}");
+    Ok(response)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A stand-in for the Advent of Code server that answers requests with the given responses, in order.
+#[cfg(feature = "test_lib")]
+pub(crate) mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves one request per `(status, body)` and returns its base url and a handle to the received requests.
+    pub(crate) fn serve(responses: &[(&str, &str)]) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<String> = responses
+            .iter()
+            .map(|(status, body)| {
+                format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            })
+            .collect();
+
+        let handle = thread::spawn(move || {
+            responses
+                .iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut BufReader::new(stream.try_clone().unwrap()));
+                    stream.write_all(response.as_bytes()).unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> String {
+        let mut request = String::new();
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                content_length = len.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        request.push_str(&String::from_utf8(body).unwrap());
+        request
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{download_with, is_private, stand_in, AocClient, AocClientError};
+    use crate::{
+        day,
+        template::{
+            input_checksums::{InputChecksums, InputStatus},
+            with_data_root,
+        },
+        year,
+    };
+
+    #[test]
+    fn downloads_inputs() {
+        let (base_url, server) = stand_in::serve(&[("200 OK", "1 2\n3 4\n")]);
+        let client = AocClient::new(&base_url, "abc\n");
+
+        let input = client.input(year!(2024), day!(6)).unwrap();
+        let request = server.join().unwrap()[0].to_lowercase();
+
+        assert_eq!(input, "1 2\n3 4\n");
+        assert!(request.starts_with("get /2024/day/6/input http/1.1"));
+        assert!(request.contains("cookie: session=abc\r\n"));
+        assert!(request.contains("user-agent: advent_of_code/"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = stand_in::serve(&[(
+            "200 OK",
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = AocClient::new(&base_url, "abc");
+
+        let response = client.submit(year!(2024), day!(6), 2, "41").unwrap();
+        let request = server.join().unwrap().remove(0);
+
+        assert_eq!(
+            response,
+            "That's the right answer! You are *one gold star* closer.\n"
+        );
+        assert!(request.starts_with("POST /2024/day/6/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=41"));
+    }
+
+    #[test]
+    fn reports_typed_errors() {
+        let (base_url, server) = stand_in::serve(&[("404 Not Found", "")]);
+        let client = AocClient::new(&base_url, "abc");
+        let result = client.puzzle(year!(2024), day!(25));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::NotFound)));
+
+        let (base_url, server) = stand_in::serve(&[("400 Bad Request", "Please log in.")]);
+        let client = AocClient::new(&base_url, "expired");
+        let result = client.input(year!(2024), day!(1));
+        server.join().unwrap();
+        assert!(matches!(result, Err(AocClientError::Unauthorized)));
+    }
+
+    #[test]
+    fn downloads_into_a_new_year() {
+        let (base_url, server) = stand_in::serve(&[
+            ("200 OK", "1 2\n"),
+            ("200 OK", "<main><article><p>Day 1</p></article></main>"),
+        ]);
+        let client = AocClient::new(&base_url, "abc");
+
+        with_data_root("new-year", |root| {
+            download_with(&client, year!(2025), day!(1), false).unwrap();
+
+            let dir = root.join("2025");
+            assert_eq!(
+                fs::read_to_string(dir.join("inputs/01.txt")).unwrap(),
+                "1 2\n"
+            );
+            assert!(dir.join("puzzles/01.md").is_file());
+            assert_eq!(
+                InputChecksums::read(year!(2025)).status(day!(1), Some("1 2\n")),
+                Some(InputStatus::Intact)
+            );
+        });
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn records_nothing_when_the_puzzle_fails() {
+        let (base_url, server) = stand_in::serve(&[("200 OK", "1 2\n"), ("404 Not Found", "")]);
+        let client = AocClient::new(&base_url, "abc");
+
+        with_data_root("failed-puzzle", |root| {
+            let result = download_with(&client, year!(2025), day!(1), false);
+
+            assert!(matches!(result, Err(AocClientError::NotFound)));
+            assert!(!root.join("2025/inputs/01.txt").exists());
+            assert_eq!(InputChecksums::read(year!(2025)), InputChecksums::default());
+        });
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn requires_private_session_files() {
+        assert!(is_private(0o100_600));
+        assert!(is_private(0o100_400));
+        assert!(!is_private(0o100_644));
+        assert!(!is_private(0o100_660));
+    }
+}
@@ -0,0 +1,230 @@
+/// Renders the stored benchmarks as a self-contained SVG bar chart, embedded in the readme.
+/// Times span several orders of magnitude between days, so the chart uses a logarithmic scale.
+use std::{fmt::Write, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::{
+    create_data_dir, get_data_dir,
+    timings::{PhaseTiming, Timing, Timings},
+    Year,
+};
+
+const BAR_WIDTH: u32 = 10;
+const GROUP_GAP: u32 = 8;
+const MARGIN_LEFT: u32 = 64;
+const MARGIN_RIGHT: u32 = 16;
+const MARGIN_TOP: u32 = 48;
+const MARGIN_BOTTOM: u32 = 32;
+const PLOT_HEIGHT: u32 = 240;
+
+/// Name and color of each phase, in the order their bars are drawn.
+const SERIES: [(&str, &str); 3] = [
+    ("Parse", "#9ca3af"),
+    ("Part 1", "#2563eb"),
+    ("Part 2", "#f59e0b"),
+];
+
+/// Renders and writes the chart of a year.
+pub fn store(year: Year, timings: &Timings) -> Result<(), io::Error> {
+    create_data_dir(year)?;
+    fs::write(get_chart_path(year), render(timings))
+}
+
+/// Path of the chart of a year, e.g. `data/2024/benchmarks.svg`.
+pub fn get_chart_path(year: Year) -> PathBuf {
+    get_data_dir(year).join("benchmarks.svg")
+}
+
+/// Renders a bar per phase, grouped by day.
+#[allow(clippy::cast_possible_truncation)]
+pub fn render(timings: &Timings) -> String {
+    let phases = |t: &Timing| [t.parse, t.part_1, t.part_2];
+
+    let nanos: Vec<u64> = timings
+        .data
+        .iter()
+        .flat_map(phases)
+        .flatten()
+        .map(|p| p.nanos.max(1))
+        .collect();
+
+    let scale = LogScale::new(
+        nanos.iter().copied().min().unwrap_or(1),
+        nanos.iter().copied().max().unwrap_or(1),
+    );
+
+    let group_width = BAR_WIDTH * SERIES.len() as u32 + GROUP_GAP;
+    let plot_width = group_width * (timings.data.len().max(1) as u32);
+    let width = MARGIN_LEFT + plot_width + MARGIN_RIGHT;
+    let height = MARGIN_TOP + PLOT_HEIGHT + MARGIN_BOTTOM;
+    let baseline = MARGIN_TOP + PLOT_HEIGHT;
+
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="{width}" height="{height}" fill="#ffffff"/>"##
+    );
+
+    // legend
+    for (i, (name, color)) in SERIES.iter().enumerate() {
+        let x = MARGIN_LEFT + i as u32 * 72;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x}" y="16" width="10" height="10" fill="{color}"/><text x="{}" y="25">{name}</text>"#,
+            x + 14
+        );
+    }
+
+    // decade grid lines
+    for exponent in scale.min_exponent..=scale.max_exponent {
+        let y = baseline - scale.height(10_u64.pow(exponent));
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{MARGIN_LEFT}" y1="{y}" x2="{}" y2="{y}" stroke="#e5e7eb"/><text x="{}" y="{}" text-anchor="end" fill="#6b7280">{:?}</text>"##,
+            MARGIN_LEFT + plot_width,
+            MARGIN_LEFT - 6,
+            y + 4,
+            Duration::from_nanos(10_u64.pow(exponent))
+        );
+    }
+
+    for (i, timing) in timings.data.iter().enumerate() {
+        let group_x = MARGIN_LEFT + i as u32 * group_width + GROUP_GAP / 2;
+
+        for (j, phase) in phases(timing).iter().enumerate() {
+            let Some(phase) = phase else {
+                continue;
+            };
+            let bar_height = scale.height(phase.nanos.max(1));
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{BAR_WIDTH}" height="{bar_height}" fill="{}"><title>Day {} {}: {}</title></rect>"#,
+                group_x + j as u32 * BAR_WIDTH,
+                baseline - bar_height,
+                SERIES[j].1,
+                timing.day,
+                SERIES[j].0,
+                format_phase(phase)
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+            group_x + BAR_WIDTH * SERIES.len() as u32 / 2,
+            baseline + 16,
+            timing.day.into_inner()
+        );
+    }
+
+    let _ = writeln!(
+        svg,
+        r##"<line x1="{MARGIN_LEFT}" y1="{baseline}" x2="{}" y2="{baseline}" stroke="#374151"/>"##,
+        MARGIN_LEFT + plot_width
+    );
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+fn format_phase(phase: &PhaseTiming) -> String {
+    match phase.samples {
+        Some(samples) => format!("{phase} @ {samples} samples"),
+        None => phase.to_string(),
+    }
+}
+
+/// Maps nanoseconds to bar heights, spanning whole decades around the measured times.
+struct LogScale {
+    min_exponent: u32,
+    max_exponent: u32,
+}
+
+impl LogScale {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn new(min_nanos: u64, max_nanos: u64) -> Self {
+        let min_exponent = min_nanos.max(1).ilog10();
+        // NOTE: round up, so that the longest bar does not touch the top of the plot.
+        let max_exponent = (max_nanos.max(1) as f64).log10().ceil() as u32;
+
+        LogScale {
+            min_exponent,
+            max_exponent: max_exponent.max(min_exponent + 1),
+        }
+    }
+
+    /// Height of a bar in pixels, measured from the lowest decade.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn height(&self, nanos: u64) -> u32 {
+        let span = f64::from(self.max_exponent - self.min_exponent);
+        let position = ((nanos as f64).log10() - f64::from(self.min_exponent)) / span;
+        (position.clamp(0.0, 1.0) * f64::from(PLOT_HEIGHT)).round() as u32
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, LogScale, PLOT_HEIGHT};
+    use crate::{
+        day,
+        template::timings::{PhaseTiming, Timing, Timings},
+    };
+
+    fn phase(nanos: u64) -> Option<PhaseTiming> {
+        Some(PhaseTiming {
+            nanos,
+            samples: Some(10),
+            stats: None,
+        })
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let scale = LogScale::new(1_000, 1_000_000);
+        assert_eq!(scale.min_exponent, 3);
+        assert_eq!(scale.max_exponent, 6);
+        assert_eq!(scale.height(1_000), 0);
+        assert_eq!(scale.height(10_000), PLOT_HEIGHT / 3);
+        assert_eq!(scale.height(1_000_000), PLOT_HEIGHT);
+    }
+
+    #[test]
+    fn renders_a_bar_per_phase() {
+        let timings = Timings {
+            data: vec![Timing {
+                day: day!(6),
+                parse: phase(8_000),
+                part_1: phase(6_000),
+                part_2: None,
+                total_nanos: 14_000.0,
+                timed_out: false,
+                heap: None,
+                source_hash: None,
+            }],
+            fingerprint: None,
+        };
+
+        let svg = render(&timings);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<title>Day 06").count(), 2);
+        assert!(svg.contains("<title>Day 06 Part 1: 6.0µs @ 10 samples</title>"));
+    }
+
+    #[test]
+    fn renders_empty_timings() {
+        let svg = render(&Timings::default());
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<title>").count(), 0);
+    }
+}
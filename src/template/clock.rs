@@ -0,0 +1,49 @@
+/// The current time for commands that depend on it, e.g. `today`. Tests pass a clock that only moves when it sleeps.
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+
+    fn sleep(&self, duration: Duration);
+}
+
+/// The clock of the system.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that stands still until it sleeps, which advances it by exactly the slept duration.
+#[cfg(feature = "test_lib")]
+pub(crate) struct FakeClock(std::cell::Cell<DateTime<Utc>>);
+
+#[cfg(feature = "test_lib")]
+impl FakeClock {
+    /// A clock at the given UTC time, e.g. `2024-12-05T04:59:58Z`.
+    pub(crate) fn at(rfc3339: &str) -> Self {
+        Self(std::cell::Cell::new(
+            DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc(),
+        ))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.0
+            .set(self.0.get() + chrono::TimeDelta::from_std(duration).unwrap());
+    }
+}
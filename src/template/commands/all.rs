@@ -1,5 +1,14 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{run_multi::run_multi, runner::BenchConfig, Timeouts, Year};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Year, is_release: bool, jobs: usize, timeouts: &Timeouts) {
+    run_multi(
+        year,
+        &year.days().collect(),
+        is_release,
+        false,
+        false,
+        jobs,
+        &BenchConfig::from_env(),
+        timeouts,
+    );
 }
@@ -0,0 +1,49 @@
+use std::{fs, process};
+
+use crate::template::{
+    aoc_client::get_input_path,
+    input_checksums::{InputChecksums, InputStatus},
+    Year,
+};
+
+/// Compares the inputs of a year with the checksums recorded when they were downloaded.
+pub fn handle(year: Year) {
+    let checksums = InputChecksums::read(year);
+    let mut intact = 0;
+    let mut problems = 0;
+
+    for day in year.days() {
+        let path = get_input_path(year, day);
+        let content = fs::read_to_string(&path).ok();
+
+        let message = match checksums.status(day, content.as_deref()) {
+            None => continue,
+            Some(InputStatus::Intact) => {
+                intact += 1;
+                continue;
+            }
+            Some(InputStatus::Unrecorded) => {
+                println!(
+                    "ℹ️  Day {day}: \"{}\" has no recorded checksum, run `cargo download {day}` to record it.",
+                    path.display()
+                );
+                continue;
+            }
+            Some(InputStatus::Edited) => "was edited since it was downloaded.".to_string(),
+            Some(InputStatus::Truncated { bytes, recorded }) => {
+                format!("is truncated, it has {bytes} of {recorded} bytes.")
+            }
+            Some(InputStatus::Missing) => "is missing.".to_string(),
+        };
+
+        problems += 1;
+        println!("⚠️  Day {day}: \"{}\" {message}", path.display());
+    }
+
+    println!("🩺 {intact} inputs of {year} are intact, {problems} have problems.");
+
+    if problems > 0 {
+        println!("Run `cargo download <day> --force` to restore an input.");
+        process::exit(1);
+    }
+}
@@ -1,14 +1,9 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::{aoc_client, Day, Year};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(year: Year, day: Day, force: bool) {
+    if let Err(e) = aoc_client::download(year, day, force) {
+        eprintln!("Failed to download day {day}: {e}");
         process::exit(1);
     };
 }
@@ -0,0 +1,141 @@
+use std::{
+    fs,
+    io::{self, Write},
+    process,
+};
+
+use crate::template::{
+    aoc_client::get_puzzle_path,
+    create_data_dir,
+    examples::{self, CodeBlock},
+    Day, Year,
+};
+
+/// Lines of a block that are shown when picking blocks interactively.
+const PREVIEW_LINES: usize = 3;
+
+/// Writes code blocks of the puzzle description to the example files.
+/// Without `selection`, the blocks are listed and the user is asked which ones to write.
+pub fn handle(year: Year, day: Day, selection: Option<&str>, overwrite: bool) {
+    let blocks = read_blocks(year, day);
+
+    if blocks.is_empty() {
+        eprintln!("The puzzle description does not contain any code blocks.");
+        process::exit(1);
+    }
+
+    let selection = match selection {
+        Some(selection) => examples::parse_selection(selection, blocks.len()),
+        None => prompt_selection(&blocks),
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Invalid selection: {e}");
+        process::exit(1);
+    });
+
+    write_blocks(year, day, &blocks, &selection, overwrite);
+}
+
+/// Writes the blocks that are introduced as examples without asking, e.g. right after a puzzle unlocked.
+pub fn handle_default(year: Year, day: Day) {
+    let blocks = read_blocks(year, day);
+    let selection = examples::default_selection(&blocks);
+
+    if selection.is_empty() {
+        println!(
+            "No code block is introduced as an example, pick them with `cargo examples {day}`."
+        );
+        return;
+    }
+
+    write_blocks(year, day, &blocks, &selection, false);
+}
+
+fn read_blocks(year: Year, day: Day) -> Vec<CodeBlock> {
+    let puzzle_path = get_puzzle_path(year, day);
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Failed to read \"{}\", run `cargo download {day}` first.",
+            puzzle_path.display()
+        );
+        process::exit(1);
+    };
+
+    examples::parse_code_blocks(&puzzle)
+}
+
+fn write_blocks(year: Year, day: Day, blocks: &[CodeBlock], selection: &[usize], overwrite: bool) {
+    let paths: Vec<_> = (0..selection.len())
+        .map(|n| examples::get_example_path(year, day, n))
+        .collect();
+
+    // scaffolded example files are empty, anything else was put there by hand.
+    if !overwrite {
+        if let Some(path) = paths
+            .iter()
+            .find(|path| fs::read_to_string(path).is_ok_and(|content| !content.trim().is_empty()))
+        {
+            eprintln!(
+                "\"{}\" already contains an example, pass `--overwrite` to replace it.",
+                path.display()
+            );
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = create_data_dir(year) {
+        eprintln!("Failed to create data directory: {e}");
+        process::exit(1);
+    }
+
+    for (block, path) in selection.iter().zip(&paths) {
+        if let Err(e) = fs::write(path, &blocks[block - 1].content) {
+            eprintln!("Failed to write \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+        println!("Wrote block {block} to \"{}\"", path.display());
+    }
+}
+
+fn prompt_selection(blocks: &[CodeBlock]) -> Result<Vec<usize>, String> {
+    for (i, block) in blocks.iter().enumerate() {
+        let lines: Vec<&str> = block.content.lines().collect();
+        println!(
+            "[{}] {} line{}{}",
+            i + 1,
+            lines.len(),
+            if lines.len() == 1 { "" } else { "s" },
+            if block.is_example { ", example" } else { "" }
+        );
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    ...");
+        }
+    }
+
+    let default = examples::default_selection(blocks);
+    let default_str = default
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    print!("Blocks to write, e.g. `1,3` [{default_str}]: ");
+    let _ = io::stdout().flush();
+
+    let mut answer = String::new();
+    io::stdin()
+        .read_line(&mut answer)
+        .map_err(|e| e.to_string())?;
+
+    match answer.trim() {
+        "" if default.is_empty() => {
+            Err("no block is introduced as an example, pick blocks by number.".into())
+        }
+        "" => Ok(default),
+        answer => examples::parse_selection(answer, blocks.len()),
+    }
+}
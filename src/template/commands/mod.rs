@@ -1,6 +1,11 @@
 pub mod all;
+pub mod doctor;
 pub mod download;
+pub mod examples;
 pub mod read;
+pub mod report;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -0,0 +1,35 @@
+use std::{fs, path::PathBuf, process};
+
+use crate::template::{
+    report::{self, ReportFormat, ReportSort},
+    timings::Timings,
+    Day, Year,
+};
+
+pub fn handle(
+    year: Year,
+    days: &[Day],
+    format: ReportFormat,
+    sort: ReportSort,
+    output: Option<PathBuf>,
+) {
+    let timings = report::select(&Timings::read_from_file(year), days, sort);
+
+    let report = match report::render(&timings, format) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Failed to render report: {e}");
+            process::exit(1);
+        }
+    };
+
+    match output {
+        Some(path) => {
+            if let Err(e) = fs::write(&path, report) {
+                eprintln!("Failed to write report to {}: {e}", path.display());
+                process::exit(1);
+            }
+        }
+        None => print!("{report}"),
+    }
+}
@@ -1,10 +1,17 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{
+    aoc_client::{get_input_path, get_puzzle_path},
+    create_data_dir,
+    examples::{self, get_example_path},
+    generated_tests::{plan_tests, update_tests},
+    get_bin_name, get_data_dir, Day, Year,
+};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -19,7 +26,7 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +34,82 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Solutions live in year directories, which cargo does not discover on its own.
+/// Adds a `[[bin]]` target for the solution to `Cargo.toml`, unless it is already present.
+fn register_bin(year: Year, day: Day, module_path: &str) -> Result<bool, io::Error> {
+    let manifest_path = "Cargo.toml";
+    let manifest = fs::read_to_string(manifest_path)?;
+    let name = format!("name = \"{}\"", get_bin_name(year, day));
+
+    if manifest.lines().any(|line| line.trim() == name) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open(manifest_path)?;
+    write!(file, "\n[[bin]]\n{name}\npath = \"{module_path}\"\n")?;
+    Ok(true)
+}
+
+/// Fills in the tests of a solution with the example answers of its puzzle description.
+/// Returns the number of tests that assert an answer.
+fn refresh_module_tests(year: Year, day: Day, module_path: &str) -> Result<usize, String> {
+    let puzzle = fs::read_to_string(get_puzzle_path(year, day)).map_err(|_| {
+        format!("the puzzle description is missing, run `cargo download {day}` first.")
+    })?;
+
+    let answers = examples::parse_answers(&puzzle);
+    let existing_examples: Vec<usize> = (0..answers.len())
+        .filter(|&n| Path::new(&get_example_path(year, day, n)).exists())
+        .collect();
+    let tests = plan_tests(&answers, &existing_examples);
+
+    let source = fs::read_to_string(module_path).map_err(|e| e.to_string())?;
+    let updated = update_tests(&source, &tests)
+        .ok_or_else(|| format!("\"{module_path}\" has no `tests` module."))?;
+    fs::write(module_path, updated.source).map_err(|e| e.to_string())?;
+
+    for name in &updated.skipped {
+        println!("Kept `{name}`, it was edited by hand. Update its expected answer yourself.");
+    }
+
+    Ok(tests
+        .iter()
+        .filter(|t| t.expected.is_some() && !updated.skipped.contains(&t.name()))
+        .count())
+}
+
+/// Regenerates the tests of a scaffolded solution, e.g. once part two is unlocked.
+pub fn refresh_tests(year: Year, day: Day) {
+    let module_path = format!("src/bin/{year}/{day}.rs");
+
+    match refresh_module_tests(year, day, &module_path) {
+        Ok(count) => println!("Updated tests of \"{module_path}\" with {count} example answers"),
+        Err(e) => {
+            eprintln!("Failed to refresh tests: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let input_path = get_input_path(year, day);
+    let example_path = get_example_path(year, day, 0);
+    let module_path = format!("src/bin/{year}/{day}.rs");
+
+    if let Err(e) = create_data_dir(year) {
+        eprintln!(
+            "Failed to create directory \"{}\": {e}",
+            get_data_dir(year).display()
+        );
+        process::exit(1);
+    }
+
+    if let Some(dir) = Path::new(&module_path).parent() {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create directory \"{}\": {e}", dir.display());
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -42,11 +121,12 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("%YEAR%", &year.to_string())
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path);
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -54,9 +134,27 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match register_bin(year, day, &module_path) {
+        Ok(true) => {
+            println!("Added binary \"{}\" to Cargo.toml", get_bin_name(year, day));
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to add binary to Cargo.toml: {e}");
+            process::exit(1);
+        }
+    }
+
+    // the puzzle is usually downloaded after scaffolding, which refreshes the tests then.
+    if fs::metadata(get_puzzle_path(year, day)).is_ok() {
+        if let Err(e) = refresh_module_tests(year, day, &module_path) {
+            eprintln!("Failed to fill in tests: {e}");
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -66,7 +164,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -75,5 +173,9 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if Year::from_env() == Some(year) {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
 }
@@ -1,9 +1,25 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::variants::COMPARE_ARG;
+use crate::template::{get_bin_name, Day, InputSource, Year};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// With `compare`, the registered variants of each part are benched against each other instead of solving.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    accept_part: Option<u8>,
+    compare: bool,
+    input: &InputSource,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -12,7 +28,8 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
+    } else if release || compare {
+        // NOTE: comparing debug builds would say little about the optimized code.
         cmd_args.push("--release".to_string());
     }
 
@@ -23,6 +40,17 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(accept_part) = accept_part {
+        cmd_args.push("--accept".to_string());
+        cmd_args.push(accept_part.to_string());
+    }
+
+    if compare {
+        cmd_args.push(COMPARE_ARG.to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -1,34 +1,114 @@
 use std::collections::HashSet;
 
 use crate::template::run_multi::run_multi;
+use crate::template::runner::BenchConfig;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{
+    benchmark_chart, compare, history, readme_benchmarks, readme_stars, source_hash, Day, Timeouts,
+    Year,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// With `compare_threshold`, the fresh timings are compared with the stored ones and the process exits
+/// with a non-zero status if any part regressed by more than the threshold (in percent).
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    memory: bool,
+    compare_threshold: Option<f64>,
+    bench_config: &BenchConfig,
+    timeouts: &Timeouts,
+) {
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            if compare_threshold.is_some() {
+                // only days with stored timings can be compared.
+                year.days()
+                    .filter(|day| stored_timings.data.iter().any(|t| t.day == *day))
+                    .collect()
+            } else if run_all {
+                year.days().collect()
             } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                // when the `--all` flag is not set, filter out days that are fully benched and unchanged since.
+                year.days()
+                    .filter(|day| {
+                        !stored_timings.is_day_complete(
+                            *day,
+                            source_hash::hash_solution(year, *day).as_deref(),
+                        )
+                    })
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(
+        year,
+        &days_to_run,
+        true,
+        true,
+        memory,
+        // NOTE: benchmarks always run one day at a time, so that days do not disturb each other.
+        1,
+        bench_config,
+        timeouts,
+    )
+    .unwrap();
+
+    let same_environment = stored_timings.matches_environment(&timings);
+
+    let regressions = compare_threshold.map(|threshold| {
+        if !same_environment {
+            eprintln!(
+                "Warning: stored timings were measured on {}, this run on {}.\n",
+                describe_environment(&stored_timings),
+                describe_environment(&timings)
+            );
+        }
+        let comparisons = compare::compare(&stored_timings, &timings);
+        compare::print_summary(&comparisons, threshold)
+    });
 
     if store {
+        // days that were not re-run would keep numbers from another environment.
+        let stale_days: Vec<String> = stored_timings
+            .data
+            .iter()
+            .filter(|t| !same_environment && !timings.data.iter().any(|n| n.day == t.day))
+            .map(|t| t.day.to_string())
+            .collect();
+
+        if !stale_days.is_empty() {
+            eprintln!(
+                "Not storing timings: stored timings were measured on {}, this run on {}.",
+                describe_environment(&stored_timings),
+                describe_environment(&timings)
+            );
+            eprintln!(
+                "Days {} would mix both. Run `cargo time --all --store` to re-bench every day.",
+                stale_days.join(", ")
+            );
+            std::process::exit(1);
+        }
+
+        if let Err(e) = history::append(year, &timings) {
+            eprintln!("Failed to append to the timing history: {e}");
+        }
+
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
+
+        if let Err(e) = benchmark_chart::store(year, &merged_timings) {
+            eprintln!("Failed to store benchmark chart: {e}");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -36,5 +116,31 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
+
+        if readme_stars::update(year).is_err() {
+            eprintln!("Failed to update star progress.");
+        }
+    }
+
+    if regressions.is_some_and(|r| r > 0) {
+        std::process::exit(1);
+    }
+}
+
+/// The environment timings were measured in, for messages about mismatches.
+fn describe_environment(timings: &Timings) -> String {
+    timings
+        .fingerprint
+        .as_ref()
+        .map_or("an unknown environment".into(), ToString::to_string)
+}
+
+pub fn history(year: Year, day: Day) {
+    match history::read(year, day) {
+        Ok(entries) => history::print_trend(day, &entries),
+        Err(e) => {
+            eprintln!("Failed to read the timing history: {e}");
+            std::process::exit(1);
+        }
     }
 }
@@ -0,0 +1,251 @@
+use std::{
+    io::{self, Write},
+    process,
+    time::Duration,
+};
+
+use crate::template::{
+    aoc_client::{self, AocClientError},
+    commands::{examples, read, scaffold},
+    countdown, Clock, Day, Year,
+};
+
+/// Puzzles can take a moment to become available after they unlock.
+const DOWNLOAD_ATTEMPTS: u32 = 5;
+const DOWNLOAD_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// The steps that set up a puzzle, so their sequence can be tested without the file system or the network.
+trait Steps {
+    fn scaffold(&mut self, year: Year, day: Day);
+    fn download(&mut self, year: Year, day: Day) -> Result<(), AocClientError>;
+    fn extract_examples(&mut self, year: Year, day: Day);
+    fn refresh_tests(&mut self, year: Year, day: Day);
+    fn read(&mut self, year: Year, day: Day);
+}
+
+/// The steps of the other commands.
+struct Commands;
+
+impl Steps for Commands {
+    fn scaffold(&mut self, year: Year, day: Day) {
+        scaffold::handle(year, day, false);
+    }
+
+    fn download(&mut self, year: Year, day: Day) -> Result<(), AocClientError> {
+        aoc_client::download(year, day, false)
+    }
+
+    fn extract_examples(&mut self, year: Year, day: Day) {
+        examples::handle_default(year, day);
+    }
+
+    fn refresh_tests(&mut self, year: Year, day: Day) {
+        scaffold::refresh_tests(year, day);
+    }
+
+    fn read(&mut self, year: Year, day: Day) {
+        read::handle(year, day);
+    }
+}
+
+/// Sets up the puzzle of the running event. With `wait`, counts down to the next puzzle and sets it up once it unlocks.
+pub fn handle(clock: &impl Clock, wait: bool) {
+    if let Err(e) = run(clock, wait, &mut Commands, &mut io::stdout()) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn run(
+    clock: &impl Clock,
+    wait: bool,
+    steps: &mut impl Steps,
+    out: &mut impl Write,
+) -> Result<(), String> {
+    // NOTE: `today` always refers to the running event, regardless of `--year`.
+    let (year, day) = if wait {
+        let (year, day, unlock) = Day::next_unlock_at(clock)
+            .ok_or("Could not determine when the next puzzle unlocks, check the system clock.")?;
+
+        let label = format!("⏳ Day {day} of {year} unlocks in");
+        countdown::wait_until(clock, unlock, &label, out);
+        let _ = writeln!(out, "🎄 Day {day} of {year} is unlocked!");
+        (year, day)
+    } else {
+        Day::today_at(clock).ok_or(
+            "`today` command can only be run while an event is running, i.e. between \
+            the 1st and its last day of december. Please use `scaffold` with a specific day, \
+            or `today --wait` to wait for the next puzzle.",
+        )?
+    };
+
+    steps.scaffold(year, day);
+    download(clock, steps, year, day).map_err(|e| format!("Failed to download day {day}: {e}"))?;
+    steps.extract_examples(year, day);
+    steps.refresh_tests(year, day);
+    steps.read(year, day);
+
+    Ok(())
+}
+
+fn download(
+    clock: &impl Clock,
+    steps: &mut impl Steps,
+    year: Year,
+    day: Day,
+) -> Result<(), AocClientError> {
+    let mut attempt = 1;
+
+    loop {
+        match steps.download(year, day) {
+            Err(AocClientError::NotFound) if attempt < DOWNLOAD_ATTEMPTS => {
+                clock.sleep(DOWNLOAD_RETRY_DELAY);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{run, Steps, DOWNLOAD_ATTEMPTS};
+    use crate::template::{
+        aoc_client::{download_with, stand_in, AocClient, AocClientError},
+        commands::examples,
+        with_data_root, Clock, Day, FakeClock, Year,
+    };
+
+    /// Records the steps and fails the first `missing_downloads` downloads, as if the puzzle was not available yet.
+    /// With a `client`, downloads and extracts the examples for real, into the data directory of the test.
+    #[derive(Default)]
+    struct FakeSteps {
+        calls: Vec<String>,
+        missing_downloads: u32,
+        client: Option<AocClient>,
+    }
+
+    impl Steps for FakeSteps {
+        fn scaffold(&mut self, year: Year, day: Day) {
+            self.calls.push(format!("scaffold {year}-{day}"));
+        }
+
+        fn download(&mut self, year: Year, day: Day) -> Result<(), AocClientError> {
+            self.calls.push("download".into());
+            if let Some(client) = &self.client {
+                return download_with(client, year, day, false);
+            }
+            if self.missing_downloads > 0 {
+                self.missing_downloads -= 1;
+                return Err(AocClientError::NotFound);
+            }
+            Ok(())
+        }
+
+        fn extract_examples(&mut self, year: Year, day: Day) {
+            self.calls.push("examples".into());
+            if self.client.is_some() {
+                examples::handle_default(year, day);
+            }
+        }
+
+        fn refresh_tests(&mut self, _: Year, _: Day) {
+            self.calls.push("tests".into());
+        }
+
+        fn read(&mut self, _: Year, _: Day) {
+            self.calls.push("read".into());
+        }
+    }
+
+    #[test]
+    fn sets_up_the_puzzle_once_it_unlocks() {
+        let clock = FakeClock::at("2024-12-05T04:59:58Z");
+        let (base_url, server) = stand_in::serve(&[
+            ("404 Not Found", ""),
+            ("404 Not Found", ""),
+            ("200 OK", "3   4\n4   3\n"),
+            (
+                "200 OK",
+                "<main><article><p>For example:</p><pre><code>3   4\n</code></pre></article></main>",
+            ),
+        ]);
+        let mut steps = FakeSteps {
+            client: Some(AocClient::new(&base_url, "abc")),
+            ..FakeSteps::default()
+        };
+        let mut out = vec![];
+
+        // NOTE: the first unlock of a year starts without any of its data.
+        with_data_root("unlock", |root| {
+            fs::create_dir(root.join("2024")).unwrap();
+            run(&clock, true, &mut steps, &mut out).unwrap();
+
+            let dir = root.join("2024");
+            assert_eq!(
+                fs::read_to_string(dir.join("inputs/05.txt")).unwrap(),
+                "3   4\n4   3\n"
+            );
+            assert!(dir.join("puzzles/05.md").is_file());
+            assert!(dir.join("input_checksums.json").is_file());
+            assert_eq!(
+                fs::read_to_string(dir.join("examples/05.txt")).unwrap(),
+                "3   4"
+            );
+        });
+        server.join().unwrap();
+
+        assert_eq!(
+            steps.calls,
+            vec![
+                "scaffold 2024-05",
+                "download",
+                "download",
+                "download",
+                "examples",
+                "tests",
+                "read"
+            ]
+        );
+        // two seconds of countdown, then two retries of the download.
+        assert_eq!(clock.now(), FakeClock::at("2024-12-05T05:00:04Z").now());
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Day 05 of 2024 unlocks in 00:00:02"));
+        assert!(out.ends_with("🎄 Day 05 of 2024 is unlocked!\n"));
+    }
+
+    #[test]
+    fn gives_up_on_missing_puzzles() {
+        let clock = FakeClock::at("2024-12-05T05:00:00Z");
+        let mut steps = FakeSteps {
+            missing_downloads: DOWNLOAD_ATTEMPTS,
+            ..FakeSteps::default()
+        };
+
+        let result = run(&clock, false, &mut steps, &mut vec![]);
+
+        assert!(result.unwrap_err().starts_with("Failed to download day 05"));
+        assert_eq!(steps.calls.len(), 1 + DOWNLOAD_ATTEMPTS as usize);
+        assert!(!steps.calls.contains(&"read".to_string()));
+    }
+
+    #[test]
+    fn waits_for_the_next_event() {
+        let clock = FakeClock::at("2024-11-30T12:00:00Z");
+        let mut steps = FakeSteps::default();
+
+        assert!(run(&clock, false, &mut steps, &mut vec![])
+            .unwrap_err()
+            .contains("`today --wait`"));
+        assert!(steps.calls.is_empty());
+
+        run(&clock, true, &mut steps, &mut vec![]).unwrap();
+        assert_eq!(steps.calls[0], "scaffold 2024-01");
+        assert_eq!(clock.now(), FakeClock::at("2024-12-01T05:00:00Z").now());
+    }
+}
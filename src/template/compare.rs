@@ -0,0 +1,212 @@
+/// Compares fresh benchmarks with the stored ones, to catch performance regressions.
+use crate::template::{
+    record::Phase,
+    stats::Stats,
+    timings::{Timing, Timings},
+    Day, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Default for how much slower (in percent) a part may get before it counts as a regression.
+pub const DEFAULT_COMPARE_THRESHOLD: f64 = 10.0;
+
+/// A change is only significant if it exceeds this many standard deviations of the combined noise.
+const NOISE_FACTOR: f64 = 2.0;
+
+/// The stored and fresh benchmark of a single phase.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub phase: Phase,
+    pub stored: Stats,
+    /// `None` if the phase no longer produced a result, e.g. because it timed out or panicked.
+    pub current: Option<Stats>,
+    /// Whether the fresh run was killed for exceeding a timeout.
+    pub timed_out: bool,
+}
+
+impl Comparison {
+    /// Relative change of the median in percent, positive if the phase got slower.
+    /// Returns `None` if the phase no longer produced a result.
+    pub fn change(&self) -> Option<f64> {
+        let current = self.current.as_ref()?;
+        let stored = self.stored.median.as_secs_f64();
+        if stored == 0.0 {
+            return Some(0.0);
+        }
+        Some((current.median.as_secs_f64() / stored - 1.0) * 100.0)
+    }
+
+    /// Whether the phase no longer produced a result, or got slower by more than `threshold` percent
+    /// and by more than the run-to-run noise of both benchmarks.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        let Some(current) = &self.current else {
+            return true;
+        };
+
+        let noise = self
+            .stored
+            .std_dev
+            .as_secs_f64()
+            .hypot(current.std_dev.as_secs_f64());
+        let delta = current.median.as_secs_f64() - self.stored.median.as_secs_f64();
+
+        self.change().is_some_and(|change| change > threshold) && delta > noise * NOISE_FACTOR
+    }
+}
+
+/// Pairs every phase of the fresh timings with its stored counterpart.
+/// Phases that were stored without stats are compared by their median alone.
+/// Stored phases without a fresh result are kept, so they count as regressions.
+pub fn compare(stored: &Timings, current: &Timings) -> Vec<Comparison> {
+    let phases = |t: &Timing| {
+        [
+            (Phase::Parse, t.parse.map(|p| p.stats_or_median())),
+            (Phase::Part(1), t.part_1.map(|p| p.stats_or_median())),
+            (Phase::Part(2), t.part_2.map(|p| p.stats_or_median())),
+        ]
+    };
+
+    let mut comparisons = vec![];
+
+    for timing in &current.data {
+        let Some(stored) = stored.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for ((phase, stored), (_, current)) in phases(stored).into_iter().zip(phases(timing)) {
+            if let Some(stored) = stored {
+                comparisons.push(Comparison {
+                    day: timing.day,
+                    phase,
+                    stored,
+                    current,
+                    timed_out: timing.timed_out,
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+/// Prints all comparisons and returns the number of regressions.
+pub fn print_summary(comparisons: &[Comparison], threshold: f64) -> usize {
+    println!("\n{ANSI_BOLD}Compared to stored timings{ANSI_RESET} (threshold {threshold}%)");
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare against.");
+        return 0;
+    }
+
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let is_regression = comparison.is_regression(threshold);
+        if is_regression {
+            regressions += 1;
+        }
+
+        let current = match (&comparison.current, comparison.change()) {
+            (Some(current), Some(change)) => format!("{:.1?} ({change:+.1}%)", current.median),
+            _ if comparison.timed_out => "timed out".into(),
+            _ => "no result".into(),
+        };
+
+        println!(
+            "  {} Day {} {:<6}  {:.1?} → {current}",
+            if is_regression { "✘" } else { "✔" },
+            comparison.day,
+            comparison.phase.label(),
+            comparison.stored.median,
+        );
+    }
+
+    println!("{regressions} regressed");
+    regressions
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, Comparison};
+    use crate::{
+        day,
+        template::{
+            record::Phase,
+            stats::Stats,
+            timings::{PhaseTiming, Timing, Timings},
+        },
+    };
+
+    fn stats(median_micros: u64, std_dev_micros: u64) -> Stats {
+        Stats {
+            std_dev: Duration::from_micros(std_dev_micros),
+            ..Stats::single(Duration::from_micros(median_micros))
+        }
+    }
+
+    fn comparison(stored: Stats, current: Stats) -> Comparison {
+        Comparison {
+            day: day!(6),
+            phase: Phase::Part(1),
+            stored,
+            current: Some(current),
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn flags_slower_parts() {
+        let c = comparison(stats(100, 1), stats(150, 1));
+        assert!((c.change().unwrap() - 50.0).abs() < 1e-9);
+        assert!(c.is_regression(10.0));
+        assert!(!c.is_regression(60.0));
+    }
+
+    #[test]
+    fn ignores_faster_parts() {
+        let c = comparison(stats(100, 1), stats(50, 1));
+        assert!(!c.is_regression(10.0));
+    }
+
+    #[test]
+    fn ignores_changes_within_noise() {
+        let c = comparison(stats(100, 20), stats(130, 20));
+        assert!(!c.is_regression(10.0));
+    }
+
+    #[test]
+    fn flags_parts_without_a_result() {
+        let timing = |part_2, timed_out| Timing {
+            day: day!(6),
+            parse: None,
+            part_1: Some(PhaseTiming::from_stats(stats(100, 1))),
+            part_2,
+            total_nanos: 0.0,
+            timed_out,
+            heap: None,
+            source_hash: None,
+        };
+        let timings = |timing| Timings {
+            data: vec![timing],
+            fingerprint: None,
+        };
+
+        let stored = timings(timing(Some(PhaseTiming::from_stats(stats(100, 1))), false));
+        let current = timings(timing(None, true));
+
+        let comparisons = compare(&stored, &current);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].is_regression(10.0));
+
+        let part_2 = &comparisons[1];
+        assert_eq!(part_2.phase, Phase::Part(2));
+        assert_eq!(part_2.current, None);
+        assert!(part_2.timed_out);
+        assert_eq!(part_2.change(), None);
+        assert!(part_2.is_regression(10.0));
+    }
+}
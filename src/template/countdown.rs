@@ -0,0 +1,106 @@
+/// A live countdown on a single terminal line, e.g. until the next puzzle unlocks.
+use std::{io::Write, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::template::Clock;
+
+/// Moves to the start of the line and clears it.
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+/// Waits until `deadline`, rewriting `label` followed by the remaining time once a second.
+pub fn wait_until(clock: &impl Clock, deadline: DateTime<Utc>, label: &str, out: &mut impl Write) {
+    loop {
+        let remaining = deadline - clock.now();
+        if remaining <= TimeDelta::zero() {
+            break;
+        }
+
+        let _ = write!(out, "{CLEAR_LINE}{label} {}", format_remaining(remaining));
+        let _ = out.flush();
+
+        // NOTE: sleeping to the next full second keeps the countdown in step with the deadline.
+        let millis = remaining.num_milliseconds() % 1000;
+        clock.sleep(Duration::from_millis(if millis > 0 {
+            millis.unsigned_abs()
+        } else {
+            1000
+        }));
+    }
+
+    let _ = write!(out, "{CLEAR_LINE}");
+    let _ = out.flush();
+}
+
+/// Formats a duration as `HH:MM:SS`, prefixed with the days if there are any. Partial seconds round up.
+fn format_remaining(remaining: TimeDelta) -> String {
+    let seconds = (remaining.num_milliseconds() + 999) / 1000;
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+
+    match days {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::{format_remaining, wait_until};
+    use crate::template::{Clock, FakeClock};
+
+    #[test]
+    fn formats_remaining_time() {
+        assert_eq!(format_remaining(TimeDelta::seconds(59)), "00:00:59");
+        assert_eq!(
+            format_remaining(TimeDelta::milliseconds(3600_001)),
+            "01:00:01"
+        );
+        assert_eq!(
+            format_remaining(TimeDelta::seconds(2 * 86400 + 3723)),
+            "2d 01:02:03"
+        );
+    }
+
+    #[test]
+    fn counts_down_to_the_deadline() {
+        let clock = FakeClock::at("2024-12-05T04:59:57.500Z");
+        let deadline = FakeClock::at("2024-12-05T05:00:00Z").now();
+        let mut out = vec![];
+
+        wait_until(&clock, deadline, "Day 05 unlocks in", &mut out);
+
+        assert_eq!(clock.now(), deadline);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            [
+                "\r\x1b[2KDay 05 unlocks in 00:00:03",
+                "\r\x1b[2KDay 05 unlocks in 00:00:02",
+                "\r\x1b[2KDay 05 unlocks in 00:00:01",
+                "\r\x1b[2K",
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn does_not_wait_for_the_past() {
+        let clock = FakeClock::at("2024-12-05T05:00:01Z");
+        let deadline = FakeClock::at("2024-12-05T05:00:00Z").now();
+        let mut out = vec![];
+
+        wait_until(&clock, deadline, "", &mut out);
+
+        assert_eq!(clock.now().to_rfc3339(), "2024-12-05T05:00:01+00:00");
+        assert_eq!(out, b"\r\x1b[2K");
+    }
+}
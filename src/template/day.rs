@@ -3,12 +3,16 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+
+#[cfg(feature = "today")]
+use crate::template::{Clock, SystemClock, Year};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Newer events are shorter, use [`Year::has_day`](crate::template::Year::has_day) to check a day against an event.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -38,23 +42,62 @@ impl Day {
     }
 
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+    /// Returns the current event and day if it's december and the event is running, `None` otherwise.
+    pub fn today() -> Option<(Year, Self)> {
+        Self::today_at(&SystemClock)
+    }
+
+    /// Like [`Day::today`], at the time of the given clock.
+    pub fn today_at(clock: &impl Clock) -> Option<(Year, Self)> {
+        let today = clock.now().with_timezone(&server_offset()?);
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        let day = Self::new(u8::try_from(today.day()).ok()?)?;
+        if today.month() == 12 && year.has_day(day) {
+            Some((year, day))
         } else {
             None
         }
     }
+
+    /// Returns the next puzzle to unlock and when it unlocks, i.e. at midnight of the server.
+    /// After the last day of an event, this is the first day of the next one.
+    pub fn next_unlock() -> Option<(Year, Self, DateTime<Utc>)> {
+        Self::next_unlock_at(&SystemClock)
+    }
+
+    /// Like [`Day::next_unlock`], at the time of the given clock.
+    pub fn next_unlock_at(clock: &impl Clock) -> Option<(Year, Self, DateTime<Utc>)> {
+        let offset = server_offset()?;
+        let now = clock.now().with_timezone(&offset);
+        let year = Year::new(u16::try_from(now.year()).ok()?)?;
+
+        // NOTE: during december, the puzzle of the current day has already unlocked at midnight.
+        let (year, day) = match now.month() {
+            12 if now.day() < u32::from(year.last_day()) => {
+                (year, Self::new(u8::try_from(now.day() + 1).ok()?)?)
+            }
+            12 => (Year::new(year.into_inner() + 1)?, Self(1)),
+            _ => (year, Self(1)),
+        };
+
+        let unlock = offset
+            .with_ymd_and_hms(i32::from(year.into_inner()), 12, u32::from(day.0), 0, 0, 0)
+            .single()?;
+
+        Some((year, day, unlock.to_utc()))
+    }
+}
+
+#[cfg(feature = "today")]
+fn server_offset() -> Option<FixedOffset> {
+    FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)
 }
 
 impl Display for Day {
@@ -108,12 +151,21 @@ pub fn all_days() -> AllDays {
 /// An iterator that yields every day of advent from the 1st to the 25th.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { current: 1 }
+        Self::until(25)
+    }
+
+    /// An iterator that stops after the given day, e.g. for events that are shorter than 25 days.
+    pub fn until(last: u8) -> Self {
+        Self {
+            current: 1,
+            last: last.min(25),
+        }
     }
 }
 
@@ -121,7 +173,7 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
         // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
@@ -155,6 +207,8 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{all_days, Day};
+    #[cfg(feature = "today")]
+    use crate::template::FakeClock;
 
     #[test]
     fn all_days_iterator() {
@@ -187,6 +241,47 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    #[cfg(feature = "today")]
+    fn today_is_in_server_time() {
+        let today = |now| Day::today_at(&FakeClock::at(now)).map(|(y, d)| (y.into_inner(), d.0));
+
+        assert_eq!(today("2024-12-05T04:59:59Z"), Some((2024, 4)));
+        assert_eq!(today("2024-12-05T05:00:00Z"), Some((2024, 5)));
+        assert_eq!(today("2024-12-01T04:59:59Z"), None);
+        assert_eq!(today("2025-12-13T12:00:00Z"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "today")]
+    fn next_unlock() {
+        let next = |now| {
+            Day::next_unlock_at(&FakeClock::at(now))
+                .map(|(y, d, unlock)| (y.into_inner(), d.0, unlock.to_rfc3339()))
+        };
+
+        assert_eq!(
+            next("2024-11-30T20:00:00Z"),
+            Some((2024, 1, "2024-12-01T05:00:00+00:00".into()))
+        );
+        assert_eq!(
+            next("2024-12-05T04:59:59Z"),
+            Some((2024, 5, "2024-12-05T05:00:00+00:00".into()))
+        );
+        assert_eq!(
+            next("2024-12-05T05:00:00Z"),
+            Some((2024, 6, "2024-12-06T05:00:00+00:00".into()))
+        );
+        assert_eq!(
+            next("2024-12-25T12:00:00Z"),
+            Some((2025, 1, "2025-12-01T05:00:00+00:00".into()))
+        );
+        assert_eq!(
+            next("2025-12-12T12:00:00Z"),
+            Some((2026, 1, "2026-12-01T05:00:00+00:00".into()))
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
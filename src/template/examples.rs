@@ -0,0 +1,338 @@
+/// Extracts example inputs from downloaded puzzle descriptions.
+/// The first selected code block becomes `NN.txt`, later ones are numbered `NN-1.txt`, `NN-2.txt`, ...
+use std::path::PathBuf;
+
+use crate::template::{get_data_dir, Day, Year};
+
+/// A fenced code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub content: String,
+    /// Whether the text before the block introduces it as an example, e.g. `For example:`.
+    pub is_example: bool,
+}
+
+/// A piece of a puzzle description, either prose or a code block.
+enum Segment<'a> {
+    Text(&'a str),
+    Block(CodeBlock),
+}
+
+fn parse_segments(markdown: &str) -> Vec<Segment<'_>> {
+    let mut segments = vec![];
+    let mut lines = markdown.lines();
+    let mut last_text = "";
+
+    while let Some(line) = lines.next() {
+        if !line.trim_start().starts_with("```") {
+            if !line.trim().is_empty() {
+                last_text = line;
+                segments.push(Segment::Text(line));
+            }
+            continue;
+        }
+
+        let content: Vec<&str> = lines
+            .by_ref()
+            .take_while(|line| !line.trim_start().starts_with("```"))
+            .collect();
+
+        segments.push(Segment::Block(CodeBlock {
+            content: content.join("\n"),
+            is_example: last_text.to_lowercase().contains("example"),
+        }));
+    }
+
+    segments
+}
+
+/// Finds the fenced code blocks of a puzzle description, in order.
+pub fn parse_code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    parse_segments(markdown)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            Segment::Block(block) => Some(block),
+            Segment::Text(_) => None,
+        })
+        .collect()
+}
+
+/// 1-based numbers of the blocks that are introduced as examples.
+pub fn default_selection(blocks: &[CodeBlock]) -> Vec<usize> {
+    blocks
+        .iter()
+        .enumerate()
+        .filter(|(_, block)| block.is_example)
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+/// Parses a comma-separated list of 1-based block numbers, e.g. `1,3`.
+pub fn parse_selection(s: &str, count: usize) -> Result<Vec<usize>, String> {
+    s.split(',')
+        .map(|n| {
+            n.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|n| (1..=count).contains(n))
+                .ok_or(format!(
+                    "`{}` is not a block number between 1 and {count}.",
+                    n.trim()
+                ))
+        })
+        .collect()
+}
+
+/// Expected answers for an example file, as stated in the puzzle description.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExampleAnswers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl ExampleAnswers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            _ => self.part_2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part_1 = Some(answer),
+            _ => self.part_2 = Some(answer),
+        }
+    }
+}
+
+/// Finds the answer that follows each example, indexed like the example files (see [`get_example_path`]).
+///
+/// The answer of an example is the last emphasized code, e.g. `` `*11*` ``, before the next example.
+/// The second part usually reuses the first example, so an answer before its first own example belongs to `NN.txt`.
+pub fn parse_answers(markdown: &str) -> Vec<ExampleAnswers> {
+    let (part_one, part_two) = markdown
+        .split_once("--- Part Two ---")
+        .unwrap_or((markdown, ""));
+
+    let mut answers: Vec<ExampleAnswers> = vec![];
+    // example blocks seen so far, i.e. the index of the next example file.
+    let mut examples = 0;
+
+    for (part, section) in [(1, part_one), (2, part_two)] {
+        let mut current = (part == 2 && examples > 0).then_some(0);
+        let mut last_answer = None;
+
+        for segment in parse_segments(section) {
+            match segment {
+                Segment::Block(block) if block.is_example => {
+                    record_answer(&mut answers, current, part, last_answer.take());
+                    current = Some(examples);
+                    examples += 1;
+                }
+                Segment::Block(_) => {}
+                Segment::Text(text) => {
+                    if let Some(answer) = last_emphasized_code(text) {
+                        last_answer = Some(answer);
+                    }
+                }
+            }
+        }
+
+        record_answer(&mut answers, current, part, last_answer);
+    }
+
+    answers
+}
+
+fn record_answer(
+    answers: &mut Vec<ExampleAnswers>,
+    example: Option<usize>,
+    part: u8,
+    answer: Option<String>,
+) {
+    if let (Some(example), Some(answer)) = (example, answer) {
+        if answers.len() <= example {
+            answers.resize(example + 1, ExampleAnswers::default());
+        }
+        answers[example].set(part, answer);
+    }
+}
+
+/// The last code in a line that is emphasized, i.e. `` `*11*` `` or `` *`11`* ``.
+fn last_emphasized_code(line: &str) -> Option<String> {
+    let mut last = None;
+    let mut rest = line;
+
+    while let Some(start) = [rest.find("`*"), rest.find("*`")]
+        .into_iter()
+        .flatten()
+        .min()
+    {
+        let opening = &rest[start..start + 2];
+        let closing = if opening == "`*" { "*`" } else { "`*" };
+        let content_start = start + 2;
+
+        let Some(len) = rest[content_start..].find(closing) else {
+            break;
+        };
+
+        let content = &rest[content_start..content_start + len];
+        if !content.is_empty() && !content.contains(['`', '*']) {
+            last = Some(content.to_string());
+        }
+        rest = &rest[content_start + len + 2..];
+    }
+
+    last
+}
+
+/// Path of the `n`-th example file of a day, starting at 0 for `NN.txt`.
+pub fn get_example_path(year: Year, day: Day, n: usize) -> PathBuf {
+    let name = match n {
+        0 => format!("{day}.txt"),
+        n => format!("{day}-{n}.txt"),
+    };
+    get_data_dir(year).join("examples").join(name)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{
+        default_selection, get_example_path, parse_answers, parse_code_blocks, parse_selection,
+        ExampleAnswers,
+    };
+    use crate::{day, year};
+
+    const PUZZLE: &str = "## --- Day 9: Disk Fragmenter ---
+
+The disk map uses a dense format:
+
+```
+12345
+```
+
+For example:
+
+```
+2333133121414131402
+```
+
+Here is a larger example, with `.` as free space:
+
+```
+00...111...2...333.44.5555.6666.777.888899
+009..111...2...333.44.5555.6666.777.88889.
+```
+";
+
+    #[test]
+    fn parses_code_blocks() {
+        let blocks = parse_code_blocks(PUZZLE);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].content, "12345");
+        assert!(!blocks[0].is_example);
+        assert_eq!(blocks[1].content, "2333133121414131402");
+        assert!(blocks[1].is_example);
+        assert_eq!(
+            blocks[2].content,
+            "00...111...2...333.44.5555.6666.777.888899\n009..111...2...333.44.5555.6666.777.88889."
+        );
+    }
+
+    #[test]
+    fn selects_examples() {
+        assert_eq!(default_selection(&parse_code_blocks(PUZZLE)), vec![2, 3]);
+        assert_eq!(parse_selection("3, 1", 3), Ok(vec![3, 1]));
+        assert!(parse_selection("4", 3).is_err());
+        assert!(parse_selection("0", 3).is_err());
+        assert!(parse_selection("a", 3).is_err());
+    }
+
+    #[test]
+    fn parses_answers() {
+        let puzzle = "## --- Day 9: Disk Fragmenter ---
+
+For example:
+
+```
+2333133121414131402
+```
+
+The first step moves a block, giving `*12*` at first:
+
+```
+0..111....22222
+```
+
+The checksum would be `*1928*`.
+
+Here is a smaller example:
+
+```
+12345
+```
+
+Its checksum is *`60`*.
+
+## --- Part Two ---
+
+For the first example, the checksum is now `*2858*`.";
+
+        assert_eq!(
+            parse_answers(puzzle),
+            vec![
+                ExampleAnswers {
+                    part_1: Some("1928".into()),
+                    part_2: Some("2858".into()),
+                },
+                ExampleAnswers {
+                    part_1: Some("60".into()),
+                    part_2: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_answers_of_new_examples_in_part_two() {
+        let puzzle = "For example:
+
+```
+a
+```
+
+Result: `*1*`.
+
+## --- Part Two ---
+
+Consider this example:
+
+```
+b
+```
+
+Result: `*2*`.";
+
+        let answers = parse_answers(puzzle);
+        assert_eq!(answers[0].get(1), Some("1"));
+        assert_eq!(answers[0].get(2), None);
+        assert_eq!(answers[1].get(2), Some("2"));
+    }
+
+    #[test]
+    fn numbers_example_files() {
+        assert_eq!(
+            get_example_path(year!(2024), day!(9), 0),
+            PathBuf::from("data/2024/examples/09.txt")
+        );
+        assert_eq!(
+            get_example_path(year!(2024), day!(9), 2),
+            PathBuf::from("data/2024/examples/09-2.txt")
+        );
+    }
+}
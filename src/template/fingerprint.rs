@@ -0,0 +1,178 @@
+/// Describes the environment benchmarks were measured in, so that timings from different machines are not mixed up.
+use std::{collections::HashMap, env, fmt::Display, process::Command, thread};
+
+use tinyjson::JsonValue;
+
+/// The machine, toolchain and build profile of a benchmark run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fingerprint {
+    /// CPU model, e.g. `Apple M2`.
+    pub cpu: Option<String>,
+    /// Number of logical cores.
+    pub cores: Option<usize>,
+    /// Operating system and architecture, e.g. `linux x86_64`.
+    pub os: String,
+    /// Output of `rustc --version`.
+    pub rustc: Option<String>,
+    /// Cargo profile the solutions were built with, e.g. `release`.
+    pub profile: String,
+}
+
+impl Fingerprint {
+    /// Captures the fingerprint of the current machine.
+    pub fn capture(is_release: bool) -> Self {
+        Fingerprint {
+            cpu: get_cpu_model(),
+            cores: thread::available_parallelism().map(usize::from).ok(),
+            os: format!("{} {}", env::consts::OS, env::consts::ARCH),
+            rustc: get_rustc_version(),
+            profile: if is_release { "release" } else { "dev" }.into(),
+        }
+    }
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cpu.as_deref().unwrap_or("unknown CPU"))?;
+        if let Some(cores) = self.cores {
+            write!(
+                f,
+                " ({cores} {})",
+                if cores == 1 { "core" } else { "cores" }
+            )?;
+        }
+        write!(
+            f,
+            ", {}, {}, {} profile",
+            self.os,
+            self.rustc.as_deref().unwrap_or("unknown rustc"),
+            self.profile
+        )
+    }
+}
+
+pub fn get_rustc_version() -> Option<String> {
+    let output = Command::new("rustc").arg("--version").output().ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|v| !v.is_empty())
+}
+
+#[cfg(target_os = "linux")]
+fn get_cpu_model() -> Option<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == "model name").then(|| value.trim().to_string())
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn get_cpu_model() -> Option<String> {
+    let output = Command::new("sysctl")
+        .args(["-n", "machdep.cpu.brand_string"])
+        .output()
+        .ok()?;
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|v| !v.is_empty())
+}
+
+#[cfg(target_os = "windows")]
+fn get_cpu_model() -> Option<String> {
+    env::var("PROCESSOR_IDENTIFIER").ok()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn get_cpu_model() -> Option<String> {
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Fingerprint> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Fingerprint) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let string = |v: &Option<String>| v.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert("cpu".into(), string(&value.cpu));
+        map.insert(
+            "cores".into(),
+            value
+                .cores
+                .map_or(JsonValue::Null, |c| JsonValue::Number(c as f64)),
+        );
+        map.insert("os".into(), JsonValue::String(value.os.clone()));
+        map.insert("rustc".into(), string(&value.rustc));
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Fingerprint {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected fingerprint to be a JSON object.")?;
+
+        let string = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        Ok(Fingerprint {
+            cpu: string("cpu"),
+            cores: json
+                .get("cores")
+                .and_then(|v| v.get::<f64>())
+                .map(|c| *c as usize),
+            os: string("os").ok_or("Expected fingerprint.os to be a string.")?,
+            rustc: string("rustc"),
+            profile: string("profile").ok_or("Expected fingerprint.profile to be a string.")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::Fingerprint;
+
+    fn get_mock_fingerprint() -> Fingerprint {
+        Fingerprint {
+            cpu: Some("Apple M2".into()),
+            cores: Some(8),
+            os: "macos aarch64".into(),
+            rustc: Some("rustc 1.83.0 (90b35a623 2024-11-26)".into()),
+            profile: "release".into(),
+        }
+    }
+
+    #[test]
+    fn formats_fingerprints() {
+        assert_eq!(
+            get_mock_fingerprint().to_string(),
+            "Apple M2 (8 cores), macos aarch64, rustc 1.83.0 (90b35a623 2024-11-26), release profile"
+        );
+
+        let unknown = Fingerprint {
+            cpu: None,
+            cores: None,
+            rustc: None,
+            ..get_mock_fingerprint()
+        };
+        assert_eq!(
+            unknown.to_string(),
+            "unknown CPU, macos aarch64, unknown rustc, release profile"
+        );
+    }
+
+    #[test]
+    fn roundtrips_fingerprints() {
+        let fingerprint = get_mock_fingerprint();
+        let json = JsonValue::from(&fingerprint);
+        assert_eq!(Fingerprint::try_from(&json).unwrap(), fingerprint);
+    }
+}
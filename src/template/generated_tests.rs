@@ -0,0 +1,292 @@
+/// Generates the tests of a scaffolded solution from the example answers of its puzzle description.
+/// Tests are matched by name, so regenerating them leaves other tests of the module untouched.
+use crate::template::examples::ExampleAnswers;
+
+/// A test that runs a part on an example file and asserts its answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GeneratedTest {
+    pub part: u8,
+    /// Number of the example file, 0 for `NN.txt`.
+    pub example: usize,
+    pub expected: Option<String>,
+}
+
+impl GeneratedTest {
+    pub fn name(&self) -> String {
+        match self.example {
+            0 => format!("test_{}", part_fn(self.part)),
+            n => format!("test_{}_example_{n}", part_fn(self.part)),
+        }
+    }
+
+    fn render(&self) -> String {
+        let part_fn = part_fn(self.part);
+
+        let input = match self.example {
+            0 => format!(
+                "        let result = {part_fn}(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));\n"
+            ),
+            n => format!(
+                "        let result = {part_fn}(&advent_of_code::template::read_file_part(\n            \"examples\", YEAR, DAY, {n},\n        ));\n"
+            ),
+        };
+
+        format!(
+            "    #[test]\n    fn {}() {{\n{input}        assert_eq!(result, {});\n    }}\n",
+            self.name(),
+            format_expected(self.expected.as_deref())
+        )
+    }
+}
+
+fn part_fn(part: u8) -> &'static str {
+    match part {
+        1 => "part_one",
+        _ => "part_two",
+    }
+}
+
+/// Numbers are asserted as such, anything else as a string.
+fn format_expected(expected: Option<&str>) -> String {
+    match expected {
+        Some(answer) if answer.parse::<i64>().is_ok() => format!("Some({answer})"),
+        Some(answer) => format!("Some({answer:?}.to_string())"),
+        None => "None".into(),
+    }
+}
+
+/// The tests for a day: `test_part_one` and `test_part_two` for `NN.txt`,
+/// and one test per known answer of the numbered example files that exist.
+pub fn plan_tests(answers: &[ExampleAnswers], existing_examples: &[usize]) -> Vec<GeneratedTest> {
+    let answer = |example: usize, part: u8| {
+        answers
+            .get(example)
+            .and_then(|a| a.get(part))
+            .map(String::from)
+    };
+
+    let mut tests: Vec<GeneratedTest> = [1, 2]
+        .into_iter()
+        .map(|part| GeneratedTest {
+            part,
+            example: 0,
+            expected: answer(0, part),
+        })
+        .collect();
+
+    for &example in existing_examples.iter().filter(|&&n| n > 0) {
+        for part in [1, 2] {
+            if let Some(expected) = answer(example, part) {
+                tests.push(GeneratedTest {
+                    part,
+                    example,
+                    expected: Some(expected),
+                });
+            }
+        }
+    }
+
+    tests
+}
+
+/// Result of [`update_tests`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct UpdatedTests {
+    pub source: String,
+    /// Names of tests that were edited by hand, so they were kept as they are.
+    pub skipped: Vec<String>,
+}
+
+/// Replaces the tests of the same name in a solution module, or appends them to its `tests` module.
+/// Existing tests are only replaced if they still look generated or still assert `None`, so tests that were
+/// edited by hand, e.g. to read another example file, are kept. Tests without an expected answer never replace anything.
+/// Returns `None` if the module has no `tests` module to add to.
+pub fn update_tests(source: &str, tests: &[GeneratedTest]) -> Option<UpdatedTests> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let mut skipped = vec![];
+
+    for test in tests {
+        let signature = format!("    fn {}() {{", test.name());
+        let rendered: Vec<String> = test.render().lines().map(String::from).collect();
+
+        if let Some(fn_line) = lines.iter().position(|line| *line == signature) {
+            if test.expected.is_none() {
+                continue;
+            }
+
+            let start = if fn_line > 0 && lines[fn_line - 1].trim() == "#[test]" {
+                fn_line - 1
+            } else {
+                fn_line
+            };
+            let end = fn_line + lines[fn_line..].iter().position(|line| line == "    }")?;
+
+            if !is_replaceable(test, &lines[start..=end]) {
+                skipped.push(test.name());
+                continue;
+            }
+
+            lines.splice(start..=end, rendered);
+        } else {
+            lines.iter().position(|line| line.trim() == "mod tests {")?;
+            // NOTE: the `tests` module is the last item of a solution, so it ends at the last closing brace.
+            let end = lines.iter().rposition(|line| line == "}")?;
+            lines.splice(end..end, std::iter::once(String::new()).chain(rendered));
+        }
+    }
+
+    Some(UpdatedTests {
+        source: lines.join("\n") + "\n",
+        skipped,
+    })
+}
+
+/// Whether an existing test still asserts `None`, or is generated apart from its expected answer.
+fn is_replaceable(test: &GeneratedTest, existing: &[String]) -> bool {
+    const ASSERT: &str = "        assert_eq!(result, ";
+
+    if existing
+        .iter()
+        .any(|line| line.trim() == "assert_eq!(result, None);")
+    {
+        return true;
+    }
+
+    let generated = GeneratedTest {
+        expected: None,
+        ..test.clone()
+    }
+    .render();
+
+    generated.lines().count() == existing.len()
+        && generated
+            .lines()
+            .zip(existing)
+            .all(|(generated, existing)| {
+                generated == existing
+                    || (generated.starts_with(ASSERT)
+                        && existing.starts_with(ASSERT)
+                        && existing.ends_with(");"))
+            })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{plan_tests, update_tests, GeneratedTest, UpdatedTests};
+    use crate::template::examples::ExampleAnswers;
+
+    const MODULE: &str = "advent_of_code::solution!(2024, 9);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(true);
+    }
+}
+";
+
+    fn answers() -> Vec<ExampleAnswers> {
+        vec![
+            ExampleAnswers {
+                part_1: Some("1928".into()),
+                part_2: None,
+            },
+            ExampleAnswers {
+                part_1: Some("60".into()),
+                part_2: Some("4,6,3".into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn plans_tests_for_existing_examples() {
+        let tests = plan_tests(&answers(), &[0, 1, 2]);
+        let names: Vec<_> = tests.iter().map(GeneratedTest::name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "test_part_one",
+                "test_part_two",
+                "test_part_one_example_1",
+                "test_part_two_example_1"
+            ]
+        );
+
+        assert_eq!(plan_tests(&answers(), &[0]).len(), 2);
+    }
+
+    #[test]
+    fn updates_tests() {
+        let UpdatedTests {
+            source: updated,
+            skipped,
+        } = update_tests(MODULE, &plan_tests(&answers(), &[0, 1])).unwrap();
+        assert!(skipped.is_empty());
+
+        assert!(updated.contains(
+            "        let result = part_one(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));\n        assert_eq!(result, Some(1928));"
+        ));
+        // the unknown answer of part two does not replace the one filled in by hand.
+        assert!(updated.contains("assert_eq!(result, Some(7));"));
+        assert!(updated.contains("    fn test_parse() {"));
+        assert!(updated.contains(
+            "    #[test]\n    fn test_part_two_example_1() {\n        let result = part_two(&advent_of_code::template::read_file_part(\n            \"examples\", YEAR, DAY, 1,\n        ));\n        assert_eq!(result, Some(\"4,6,3\".to_string()));\n    }\n}\n"
+        ));
+
+        // regenerating is stable.
+        let again = update_tests(&updated, &plan_tests(&answers(), &[0, 1])).unwrap();
+        assert_eq!(again.source, updated);
+        assert!(again.skipped.is_empty());
+    }
+
+    #[test]
+    fn requires_a_tests_module() {
+        assert_eq!(
+            update_tests("pub fn part_one() {}\n", &plan_tests(&[], &[0])),
+            None
+        );
+    }
+
+    #[test]
+    fn keeps_tests_edited_by_hand() {
+        let edited = MODULE.replace(
+            "        let result = part_two(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));\n        assert_eq!(result, Some(7));",
+            "        let result = part_two(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, 2));\n        assert_eq!(result, Some(117440));",
+        );
+        let answers = vec![ExampleAnswers {
+            part_1: Some("1928".into()),
+            part_2: Some("2858".into()),
+        }];
+
+        let updated = update_tests(&edited, &plan_tests(&answers, &[0])).unwrap();
+
+        assert_eq!(updated.skipped, vec!["test_part_two"]);
+        assert!(updated.source.contains("assert_eq!(result, Some(1928));"));
+        assert!(updated
+            .source
+            .contains("read_file_part(\"examples\", YEAR, DAY, 2)"));
+        assert!(updated.source.contains("assert_eq!(result, Some(117440));"));
+        assert!(!updated.source.contains("2858"));
+    }
+}
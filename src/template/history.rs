@@ -0,0 +1,313 @@
+/// Append-only log of stored benchmarks, kept in `data/YYYY/timings_history.jsonl`.
+/// Unlike `data/YYYY/timings.json`, older values are never overwritten, so the performance of a day can be followed over time.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    create_data_dir,
+    fingerprint::get_rustc_version,
+    get_data_dir,
+    timings::{Timing, Timings},
+    Day, Year,
+};
+
+/// The benchmark of a single day at one point in time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub day: Day,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, suffixed with `-dirty` if there were uncommitted changes.
+    pub commit: Option<String>,
+    /// Output of `rustc --version`.
+    pub rustc: Option<String>,
+    pub parse: Option<Duration>,
+    pub part_1: Option<Duration>,
+    pub part_2: Option<Duration>,
+}
+
+impl HistoryEntry {
+    fn new(timing: &Timing, timestamp: u64, commit: Option<String>, rustc: Option<String>) -> Self {
+        HistoryEntry {
+            day: timing.day,
+            timestamp,
+            commit,
+            rustc,
+            parse: timing.parse.map(|p| p.duration()),
+            part_1: timing.part_1.map(|p| p.duration()),
+            part_2: timing.part_2.map(|p| p.duration()),
+        }
+    }
+}
+
+/// Appends an entry for every timed day.
+pub fn append(year: Year, timings: &Timings) -> Result<(), io::Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = get_commit();
+    let rustc = get_rustc_version();
+
+    create_data_dir(year)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_path(year))?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry::new(timing, timestamp, commit.clone(), rustc.clone());
+        let json = JsonValue::from(&entry)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        writeln!(file, "{json}")?;
+    }
+
+    Ok(())
+}
+
+/// Reads all entries of a day, oldest first. A missing file yields no entries.
+pub fn read(year: Year, day: Day) -> Result<Vec<HistoryEntry>, String> {
+    let Ok(contents) = fs::read_to_string(get_history_path(year)) else {
+        return Ok(vec![]);
+    };
+
+    let mut entries = parse_entries(&contents)?;
+    entries.retain(|e| e.day == day);
+    entries.sort_by_key(|e| e.timestamp);
+    Ok(entries)
+}
+
+/// Prints the entries of a day, with the change of each part relative to the entry before it.
+pub fn print_trend(day: Day, entries: &[HistoryEntry]) {
+    if entries.is_empty() {
+        println!("No history for day {day}. Store timings with `cargo time {day} --store`.");
+        return;
+    }
+
+    println!(
+        "{:<10}  {:<14}  {:<22}  {:<20}  {:<20}  Part 2",
+        "Date", "Commit", "Toolchain", "Parse", "Part 1"
+    );
+
+    let mut previous: Option<&HistoryEntry> = None;
+
+    for entry in entries {
+        let column = |get: fn(&HistoryEntry) -> Option<Duration>| {
+            format_change(get(entry), previous.and_then(get))
+        };
+
+        println!(
+            "{:<10}  {:<14}  {:<22}  {:<20}  {:<20}  {}",
+            format_date(entry.timestamp),
+            entry.commit.as_deref().unwrap_or("-"),
+            entry
+                .rustc
+                .as_deref()
+                .and_then(|v| v.strip_prefix("rustc "))
+                .and_then(|v| v.split_whitespace().next())
+                .unwrap_or("-"),
+            column(|e| e.parse),
+            column(|e| e.part_1),
+            column(|e| e.part_2),
+        );
+
+        previous = Some(entry);
+    }
+}
+
+fn get_history_path(year: Year) -> PathBuf {
+    get_data_dir(year).join("timings_history.jsonl")
+}
+
+fn parse_entries(contents: &str) -> Result<Vec<HistoryEntry>, String> {
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("history entry is not valid JSON."))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+fn get_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+
+    let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let is_dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|o| !o.stdout.is_empty());
+
+    Some(if is_dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+/// Formats a duration along with its relative change to a previous one, e.g. `1.2ms (-4.0%)`.
+fn format_change(current: Option<Duration>, previous: Option<Duration>) -> String {
+    let Some(current) = current else {
+        return "-".into();
+    };
+
+    match previous.filter(|p| !p.is_zero()) {
+        Some(previous) => {
+            let change = (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+            format!("{current:.1?} ({change:+.1}%)")
+        }
+        None => format!("{current:.1?}"),
+    }
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD` (UTC).
+/// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn format_date(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let nanos = |d: Option<Duration>| {
+            d.map_or(JsonValue::Null, |d| JsonValue::Number(d.as_nanos() as f64))
+        };
+        let string = |s: &Option<String>| s.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("commit".into(), string(&value.commit));
+        map.insert("rustc".into(), string(&value.rustc));
+        map.insert("parse_nanos".into(), nanos(value.parse));
+        map.insert("part_1_nanos".into(), nanos(value.part_1));
+        map.insert("part_2_nanos".into(), nanos(value.part_2));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected history.day to be a Day struct.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        let nanos = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|v| Duration::from_nanos(v as u64))
+        };
+        let string = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        Ok(HistoryEntry {
+            day,
+            timestamp: timestamp as u64,
+            commit: string("commit"),
+            rustc: string("rustc"),
+            parse: nanos("parse_nanos"),
+            part_1: nanos("part_1_nanos"),
+            part_2: nanos("part_2_nanos"),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{format_change, format_date, parse_entries, HistoryEntry};
+    use crate::day;
+
+    fn get_mock_entry() -> HistoryEntry {
+        HistoryEntry {
+            day: day!(6),
+            timestamp: 1_734_000_000,
+            commit: Some("3b0cea7".into()),
+            rustc: Some("rustc 1.85.0-nightly (a2545fd6f 2024-11-28)".into()),
+            parse: None,
+            part_1: Some(Duration::from_micros(120)),
+            part_2: Some(Duration::from_millis(80)),
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = get_mock_entry();
+        let json = JsonValue::from(&entry).stringify().unwrap();
+        assert_eq!(parse_entries(&json).unwrap(), vec![entry]);
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(1_734_000_000), "2024-12-12");
+        assert_eq!(format_date(1_709_208_000), "2024-02-29");
+    }
+
+    #[test]
+    fn formats_changes() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            format_change(Some(ms(90)), Some(ms(100))),
+            "90.0ms (-10.0%)"
+        );
+        assert_eq!(
+            format_change(Some(ms(150)), Some(ms(100))),
+            "150.0ms (+50.0%)"
+        );
+        assert_eq!(format_change(Some(ms(100)), None), "100.0ms");
+        assert_eq!(format_change(None, Some(ms(100))), "-");
+    }
+}
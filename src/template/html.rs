@@ -0,0 +1,163 @@
+//! Just enough HTML handling for the pages of Advent of Code: extracting `<article>`s and rendering them as markdown.
+//! Puzzle descriptions only use a small set of tags, anything else is reduced to its text.
+
+/// Inner HTML of all `<article>` elements, in document order.
+pub fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(len) = rest[content_start..].find("</article>") else {
+            break;
+        };
+
+        articles.push(&rest[content_start..content_start + len]);
+        rest = &rest[content_start + len + "</article>".len()..];
+    }
+
+    articles
+}
+
+/// Renders HTML as markdown. Headings, paragraphs, emphasis, code, lists and links are kept, other tags are dropped.
+pub fn to_markdown(html: &str) -> String {
+    let mut md = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<Option<String>> = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut md, &rest[..start], in_pre);
+
+        let Some(len) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h1" | "h2" | "h3", false) => md.push_str("## "),
+            ("h1" | "h2" | "h3" | "p" | "ul", true) => end_line(&mut md, 2),
+            ("pre", false) => {
+                in_pre = true;
+                md.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                end_line(&mut md, 1);
+                md.push_str("```");
+                end_line(&mut md, 2);
+            }
+            ("code", _) if !in_pre => md.push('`'),
+            ("em", _) => md.push('*'),
+            ("li", false) => md.push_str("- "),
+            ("li", true) => end_line(&mut md, 1),
+            ("br", _) => md.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href"));
+                md.push('[');
+            }
+            ("a", true) => match links.pop().flatten() {
+                Some(href) => md.push_str(&format!("]({href})")),
+                None => md.push(']'),
+            },
+            _ => {}
+        }
+    }
+
+    push_text(&mut md, rest, in_pre);
+
+    format!("{}\n", md.trim())
+}
+
+/// Ends the current line, followed by `newlines - 1` blank lines, unless the markdown already ends that way.
+fn end_line(md: &mut String, newlines: usize) {
+    let existing = md.len() - md.trim_end_matches('\n').len();
+    for _ in existing..newlines {
+        md.push('\n');
+    }
+}
+
+/// Value of an attribute of an opening tag, e.g. the `href` of `a href="/2024/day/6"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let (_, value) = tag.split_once(&format!("{name}=\""))?;
+    let (value, _) = value.split_once('"')?;
+    Some(decode_entities(value))
+}
+
+fn push_text(md: &mut String, text: &str, in_pre: bool) {
+    // outside of `<pre>`, line breaks in the source are only formatting.
+    if in_pre {
+        md.push_str(&decode_entities(text));
+    } else {
+        md.push_str(&decode_entities(&text.replace('\n', " ")));
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_articles, to_markdown};
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><h2>One</h2></article><p>x</p><article>Two</article></main>"#;
+        assert_eq!(extract_articles(html), vec!["<h2>One</h2>", "Two"]);
+        assert!(extract_articles("<main></main>").is_empty());
+    }
+
+    #[test]
+    fn renders_puzzles() {
+        let html = concat!(
+            "<h2>--- Day 6: Guard Gallivant ---</h2>",
+            "<p>The map shows <em>obstructions</em> as <code>#</code>, see <a href=\"/2024/day/5\">yesterday</a>.</p>",
+            "<pre><code>....#\n.^..&lt;\n</code></pre>",
+            "<ul><li>Turn <em>right</em>.</li><li>Step.</li></ul>",
+            "<p>How many positions?</p>"
+        );
+
+        assert_eq!(
+            to_markdown(html),
+            [
+                "## --- Day 6: Guard Gallivant ---",
+                "",
+                "The map shows *obstructions* as `#`, see [yesterday](/2024/day/5).",
+                "",
+                "```",
+                "....#",
+                ".^..<",
+                "```",
+                "",
+                "- Turn *right*.",
+                "- Step.",
+                "",
+                "How many positions?",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::template::{read_file, read_file_part, Day, Year};
+
+/// Where a solution reads its input from.
+/// Selected with `--input <path>`, `--input -` (stdin) or `--example [N]`, defaults to `data/YYYY/inputs/NN.txt`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Inputs,
+    /// `data/YYYY/examples/NN.txt`, or `data/YYYY/examples/NN-K.txt` if a number is given.
+    Examples(Option<u8>),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the input source from the arguments the solution binary was called with.
+    pub fn from_env() -> Self {
+        Self::from_args(&env::args().collect::<Vec<_>>())
+    }
+
+    pub fn from_args(args: &[String]) -> Self {
+        if let Some(i) = args.iter().position(|x| x == "--input") {
+            return match args.get(i + 1).map(String::as_str) {
+                Some("-") => InputSource::Stdin,
+                Some(path) => InputSource::File(path.into()),
+                None => InputSource::Inputs,
+            };
+        }
+
+        if let Some(i) = args.iter().position(|x| x == "--example") {
+            return InputSource::Examples(args.get(i + 1).and_then(|x| x.parse().ok()));
+        }
+
+        InputSource::Inputs
+    }
+
+    /// Arguments that pass this input source on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Inputs => vec![],
+            InputSource::Examples(None) => vec!["--example".into()],
+            InputSource::Examples(Some(part)) => vec!["--example".into(), part.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.to_string_lossy().into()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Whether this is the puzzle input, i.e. results are eligible for submission.
+    pub fn is_puzzle_input(&self) -> bool {
+        *self == InputSource::Inputs
+    }
+
+    #[must_use]
+    pub fn read(&self, year: Year, day: Day) -> String {
+        match self {
+            InputSource::Inputs => read_file("inputs", year, day),
+            InputSource::Examples(None) => read_file("examples", year, day),
+            InputSource::Examples(Some(part)) => read_file_part("examples", year, day, *part),
+            InputSource::File(path) => fs::read_to_string(path).expect("could not open input file"),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .expect("could not read input from stdin");
+                input
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn defaults_to_inputs() {
+        let source = InputSource::from_args(&args(&["06", "--time"]));
+        assert_eq!(source, InputSource::Inputs);
+    }
+
+    #[test]
+    fn parses_input_files() {
+        let source = InputSource::from_args(&args(&["06", "--input", "foo.txt"]));
+        assert_eq!(source, InputSource::File("foo.txt".into()));
+        assert_eq!(source.to_args(), args(&["--input", "foo.txt"]));
+    }
+
+    #[test]
+    fn parses_stdin() {
+        let source = InputSource::from_args(&args(&["06", "--input", "-"]));
+        assert_eq!(source, InputSource::Stdin);
+    }
+
+    #[test]
+    fn parses_examples() {
+        let source = InputSource::from_args(&args(&["06", "--example"]));
+        assert_eq!(source, InputSource::Examples(None));
+
+        let source = InputSource::from_args(&args(&["06", "--example", "2", "--time"]));
+        assert_eq!(source, InputSource::Examples(Some(2)));
+        assert_eq!(source.to_args(), args(&["--example", "2"]));
+    }
+}
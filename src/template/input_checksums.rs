@@ -0,0 +1,343 @@
+/// Checksums of downloaded inputs, stored per year in `data/YYYY/input_checksums.json`.
+/// Used to refuse downloads that would silently replace an input, and by `cargo doctor` to find inputs that changed since.
+use std::{collections::HashMap, fs, io, path::PathBuf, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{create_data_dir, get_data_dir, source_hash, Day, Year};
+
+/// Checksum of the content of an input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checksum {
+    pub hash: String,
+    pub bytes: u64,
+    /// Hashes of the content up to the end of each line, see [`line_hashes`].
+    /// Empty for checksums that were recorded before, which can not tell a truncated input from an edited one.
+    pub lines: Vec<String>,
+}
+
+impl Checksum {
+    pub fn of(content: &str) -> Self {
+        Self {
+            hash: source_hash::hash(&[content.as_bytes()]),
+            bytes: content.len() as u64,
+            lines: line_hashes(content),
+        }
+    }
+
+    /// Whether both checksums were taken of the same content.
+    fn matches(&self, other: &Checksum) -> bool {
+        self.hash == other.hash && self.bytes == other.bytes
+    }
+
+    /// Whether `content` is the start of the content of this checksum, cut off at the end of a line.
+    fn is_prefix(&self, content: &str) -> bool {
+        if content.is_empty() {
+            return true;
+        }
+        if !content.ends_with('\n') || content.len() as u64 >= self.bytes {
+            return false;
+        }
+
+        let lines = line_hashes(content);
+        lines.last() == self.lines.get(lines.len() - 1)
+    }
+}
+
+/// Chains the hash of each line that ends in a newline with the hash of the lines before it.
+/// The hash of a line therefore covers everything before it, so it identifies a prefix of the content.
+fn line_hashes(content: &str) -> Vec<String> {
+    let mut hashes: Vec<String> = vec![];
+
+    for line in content
+        .split_inclusive('\n')
+        .filter(|line| line.ends_with('\n'))
+    {
+        let previous = hashes.last().map_or("", String::as_str);
+        hashes.push(source_hash::hash(&[previous.as_bytes(), line.as_bytes()]));
+    }
+
+    hashes
+}
+
+/// The recorded checksums of the inputs of a year.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputChecksums {
+    pub days: HashMap<Day, Checksum>,
+}
+
+/// State of an input compared to its recorded checksum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputStatus {
+    Intact,
+    Edited,
+    /// The input is the start of the one that was downloaded, e.g. because of a partial copy.
+    /// Only inputs that end at a line can be recognized, any other change is [`InputStatus::Edited`].
+    Truncated {
+        bytes: u64,
+        recorded: u64,
+    },
+    Missing,
+    /// The input was never downloaded, e.g. because it was pasted by hand.
+    Unrecorded,
+}
+
+/// Reason for refusing to write a downloaded input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputConflict {
+    /// The server returned a different input than the one that was downloaded before, e.g. for another account.
+    Recorded,
+    /// The input on disk would be replaced, e.g. because it was edited by hand.
+    Local,
+}
+
+impl InputChecksums {
+    /// Reads the checksums of a year. If not present or invalid, returns no checksums.
+    pub fn read(year: Year) -> Self {
+        fs::read_to_string(get_checksums_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(InputChecksums::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn store(&self, year: Year) -> Result<(), io::Error> {
+        create_data_dir(year)?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_checksums_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    pub fn record(&mut self, day: Day, content: &str) {
+        self.days.insert(day, Checksum::of(content));
+    }
+
+    /// Checks whether a downloaded input can be written without replacing something.
+    /// Scaffolded inputs are empty, so they can always be replaced.
+    pub fn check_download(
+        &self,
+        day: Day,
+        local: Option<&str>,
+        downloaded: &str,
+    ) -> Result<(), InputConflict> {
+        let checksum = Checksum::of(downloaded);
+
+        if self
+            .days
+            .get(&day)
+            .is_some_and(|recorded| !recorded.matches(&checksum))
+        {
+            return Err(InputConflict::Recorded);
+        }
+
+        match local {
+            Some(local) if !local.is_empty() && !Checksum::of(local).matches(&checksum) => {
+                Err(InputConflict::Local)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Compares an input with its recorded checksum. Returns `None` if there is neither an input nor a checksum.
+    pub fn status(&self, day: Day, content: Option<&str>) -> Option<InputStatus> {
+        match (self.days.get(&day), content) {
+            // NOTE: scaffolded inputs are empty until they are downloaded.
+            (None, None | Some("")) => None,
+            (None, Some(_)) => Some(InputStatus::Unrecorded),
+            (Some(_), None) => Some(InputStatus::Missing),
+            (Some(recorded), Some(content)) => Some(if Checksum::of(content).matches(recorded) {
+                InputStatus::Intact
+            } else if recorded.is_prefix(content) {
+                InputStatus::Truncated {
+                    bytes: content.len() as u64,
+                    recorded: recorded.bytes,
+                }
+            } else {
+                InputStatus::Edited
+            }),
+        }
+    }
+}
+
+fn get_checksums_path(year: Year) -> PathBuf {
+    get_data_dir(year).join("input_checksums.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&InputChecksums> for JsonValue {
+    fn from(value: &InputChecksums) -> Self {
+        let map: HashMap<String, JsonValue> = value
+            .days
+            .iter()
+            .map(|(day, checksum)| {
+                let mut entry: HashMap<String, JsonValue> = HashMap::new();
+                entry.insert("hash".into(), JsonValue::String(checksum.hash.clone()));
+                entry.insert("bytes".into(), JsonValue::Number(checksum.bytes as f64));
+                entry.insert(
+                    "lines".into(),
+                    JsonValue::Array(
+                        checksum
+                            .lines
+                            .iter()
+                            .map(|hash| JsonValue::String(hash.clone()))
+                            .collect(),
+                    ),
+                );
+                (day.to_string(), JsonValue::Object(entry))
+            })
+            .collect();
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for InputChecksums {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let days = json
+            .iter()
+            .map(|(day, entry)| {
+                let day = Day::from_str(day).map_err(|e| e.to_string())?;
+                let entry = entry
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("expected checksum to be an object.")?;
+                let hash = entry
+                    .get("hash")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("expected checksum to have a hash.")?;
+                let bytes = entry
+                    .get("bytes")
+                    .and_then(|v| v.get::<f64>())
+                    .ok_or("expected checksum to have a length.")?;
+                // NOTE: checksums recorded before the lines were hashed do not have them.
+                let lines = entry
+                    .get("lines")
+                    .and_then(|v| v.get::<Vec<JsonValue>>())
+                    .map(|lines| {
+                        lines
+                            .iter()
+                            .filter_map(|line| line.get::<String>().cloned())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                Ok((
+                    day,
+                    Checksum {
+                        hash: hash.clone(),
+                        bytes: *bytes as u64,
+                        lines,
+                    },
+                ))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(InputChecksums { days })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{InputChecksums, InputConflict, InputStatus};
+    use crate::day;
+
+    const INPUT: &str = "3   4\n4   3\n2   5\n";
+
+    fn checksums() -> InputChecksums {
+        let mut checksums = InputChecksums::default();
+        checksums.record(day!(1), INPUT);
+        checksums
+    }
+
+    #[test]
+    fn roundtrips_checksums() {
+        let json = JsonValue::from(&checksums()).stringify().unwrap();
+        assert_eq!(InputChecksums::try_from(json).unwrap(), checksums());
+    }
+
+    #[test]
+    fn reads_checksums_without_lines() {
+        let json = r#"{"01": {"hash": "0123456789abcdef", "bytes": 18}}"#;
+        let checksums = InputChecksums::try_from(json.to_string()).unwrap();
+
+        assert!(checksums.days[&day!(1)].lines.is_empty());
+        assert_eq!(
+            checksums.status(day!(1), Some("3   4\n")),
+            Some(InputStatus::Edited)
+        );
+    }
+
+    #[test]
+    fn refuses_conflicting_downloads() {
+        let checksums = checksums();
+
+        assert_eq!(checksums.check_download(day!(1), None, INPUT), Ok(()));
+        assert_eq!(checksums.check_download(day!(1), Some(""), INPUT), Ok(()));
+        assert_eq!(
+            checksums.check_download(day!(1), Some(INPUT), "1   1\n"),
+            Err(InputConflict::Recorded)
+        );
+        assert_eq!(
+            checksums.check_download(day!(2), Some("pasted\n"), INPUT),
+            Err(InputConflict::Local)
+        );
+        assert_eq!(
+            checksums.check_download(day!(2), Some(INPUT), INPUT),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn checks_inputs() {
+        let checksums = checksums();
+
+        assert_eq!(
+            checksums.status(day!(1), Some(INPUT)),
+            Some(InputStatus::Intact)
+        );
+        assert_eq!(
+            checksums.status(day!(1), Some("3   4\n4   3\n2   6\n")),
+            Some(InputStatus::Edited)
+        );
+        assert_eq!(
+            checksums.status(day!(1), Some("3   4\n4   3\n")),
+            Some(InputStatus::Truncated {
+                bytes: 12,
+                recorded: 18
+            })
+        );
+        // shorter, but not the start of the input.
+        assert_eq!(
+            checksums.status(day!(1), Some("3   4\n4   4\n")),
+            Some(InputStatus::Edited)
+        );
+        assert_eq!(
+            checksums.status(day!(1), Some("3   4\n4")),
+            Some(InputStatus::Edited)
+        );
+        assert_eq!(checksums.status(day!(1), None), Some(InputStatus::Missing));
+        assert_eq!(
+            checksums.status(day!(1), Some("")),
+            Some(InputStatus::Truncated {
+                bytes: 0,
+                recorded: 18
+            })
+        );
+        assert_eq!(
+            checksums.status(day!(2), Some(INPUT)),
+            Some(InputStatus::Unrecorded)
+        );
+        assert_eq!(checksums.status(day!(2), Some("")), None);
+    }
+}
@@ -0,0 +1,175 @@
+/// Moves data of the layout before years were supported, e.g. `data/inputs/01.txt`, to `data/YYYY/inputs/01.txt`.
+/// Inputs, puzzles, timings and answers are not tracked by git, so they stay behind when the repository is upgraded.
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Year, DATA_FOLDERS};
+
+/// Files that used to live directly in `data/`, next to the folders that are now in `data/YYYY`.
+const LEGACY_FILES: [&str; 3] = ["timings.json", "timings_history.jsonl", "benchmarks.svg"];
+
+#[derive(Debug)]
+pub enum MigrationError {
+    /// Both layouts hold a different version of the same file, so neither can be dropped.
+    Conflict {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    Io(io::Error),
+}
+
+impl Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationError::Conflict { from, to } => write!(
+                f,
+                "\"{}\" and \"{}\" both exist and differ, keep one of them and remove the other.",
+                from.display(),
+                to.display()
+            ),
+            MigrationError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for MigrationError {
+    fn from(e: io::Error) -> Self {
+        MigrationError::Io(e)
+    }
+}
+
+/// Moves the legacy files in `data_dir` to the directory of `year` and returns where they were moved to.
+/// Once everything is moved, this does nothing.
+pub fn migrate(data_dir: &Path, year: Year) -> Result<Vec<PathBuf>, MigrationError> {
+    let year_dir = data_dir.join(year.to_string());
+    let mut moved = vec![];
+
+    for name in LEGACY_FILES {
+        if let Some(to) = move_file(&data_dir.join(name), &year_dir.join(name))? {
+            moved.push(to);
+        }
+    }
+
+    for name in DATA_FOLDERS {
+        let from_dir = data_dir.join(name);
+        let Ok(entries) = fs::read_dir(&from_dir) else {
+            continue;
+        };
+
+        for entry in entries {
+            let from = entry?.path();
+            // NOTE: `.keep` files are tracked, git already moved them.
+            if !from.is_file() || from.file_name().is_some_and(|name| name == ".keep") {
+                continue;
+            }
+            let to = year_dir
+                .join(name)
+                .join(from.file_name().unwrap_or_default());
+            if let Some(to) = move_file(&from, &to)? {
+                moved.push(to);
+            }
+        }
+
+        // only succeeds if nothing is left behind.
+        let _ = fs::remove_file(from_dir.join(".keep"));
+        let _ = fs::remove_dir(&from_dir);
+    }
+
+    Ok(moved)
+}
+
+/// Moves a file unless the destination already holds it. Empty destinations, e.g. scaffolded inputs, are replaced.
+fn move_file(from: &Path, to: &Path) -> Result<Option<PathBuf>, MigrationError> {
+    if !from.is_file() {
+        return Ok(None);
+    }
+
+    match fs::read(to) {
+        Ok(existing) if existing == fs::read(from)? => {
+            fs::remove_file(from)?;
+            Ok(None)
+        }
+        Ok(existing) if !existing.is_empty() => Err(MigrationError::Conflict {
+            from: from.to_path_buf(),
+            to: to.to_path_buf(),
+        }),
+        _ => {
+            if let Some(dir) = to.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::rename(from, to)?;
+            Ok(Some(to.to_path_buf()))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, path::PathBuf, process};
+
+    use super::{migrate, MigrationError};
+    use crate::year;
+
+    fn data_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-legacy-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("2024/inputs")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn moves_legacy_data() {
+        let dir = data_dir("moves");
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::write(dir.join("inputs/01.txt"), "3   4\n").unwrap();
+        fs::write(dir.join("2024/inputs/02.txt"), "").unwrap();
+        fs::write(dir.join("timings.json"), "{}").unwrap();
+
+        let mut moved = migrate(&dir, year!(2024)).unwrap();
+        moved.sort();
+        assert_eq!(
+            moved,
+            vec![
+                dir.join("2024/inputs/01.txt"),
+                dir.join("2024/timings.json")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("2024/inputs/01.txt")).unwrap(),
+            "3   4\n"
+        );
+        assert!(!dir.join("inputs").exists());
+        assert!(!dir.join("timings.json").exists());
+
+        // moving again finds nothing.
+        assert!(migrate(&dir, year!(2024)).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn keeps_conflicting_data() {
+        let dir = data_dir("conflict");
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::write(dir.join("inputs/01.txt"), "old\n").unwrap();
+        fs::write(dir.join("2024/inputs/01.txt"), "new\n").unwrap();
+
+        assert!(matches!(
+            migrate(&dir, year!(2024)),
+            Err(MigrationError::Conflict { .. })
+        ));
+        assert_eq!(
+            fs::read_to_string(dir.join("inputs/01.txt")).unwrap(),
+            "old\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("2024/inputs/01.txt")).unwrap(),
+            "new\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,68 +1,220 @@
-use std::{env, fs};
+use std::{env, fs, io, path::PathBuf};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod legacy_data;
+pub mod registry;
 pub mod runner;
+pub mod variants;
 
+#[cfg(feature = "today")]
+pub use clock::{Clock, SystemClock};
+pub use compare::DEFAULT_COMPARE_THRESHOLD;
 pub use day::*;
+pub use input::InputSource;
+pub use report::{ReportFormat, ReportSort};
+pub use run_multi::Timeouts;
+pub use year::*;
 
+#[cfg(all(feature = "today", feature = "test_lib"))]
+pub(crate) use clock::FakeClock;
+
+mod answers;
+mod benchmark_chart;
+#[cfg(feature = "today")]
+mod clock;
+mod compare;
+#[cfg(feature = "today")]
+mod countdown;
 mod day;
+mod examples;
+mod fingerprint;
+mod generated_tests;
+mod history;
+mod html;
+mod input;
+mod input_checksums;
 mod readme_benchmarks;
+mod readme_stars;
+mod record;
+mod report;
 mod run_multi;
+mod source_hash;
+mod stats;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Folders in the data directory of every year.
+pub(crate) const DATA_FOLDERS: [&str; 5] =
+    ["inputs", "puzzles", "examples", "answers", "submissions"];
+
+#[cfg(feature = "test_lib")]
+thread_local! {
+    /// Directory that replaces `data` for the tests on this thread, see [`with_data_root`].
+    static DATA_ROOT: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Directory that holds the data of a year, e.g. `data/2024`.
+#[must_use]
+pub fn get_data_dir(year: Year) -> PathBuf {
+    #[cfg(feature = "test_lib")]
+    if let Some(root) = DATA_ROOT.with_borrow(Clone::clone) {
+        return root.join(year.to_string());
+    }
+
+    PathBuf::from("data").join(year.to_string())
+}
+
+/// Runs `f` with the data of every year in a fresh, empty `root`, so tests do not touch `data`.
+#[cfg(feature = "test_lib")]
+pub(crate) fn with_data_root<T>(name: &str, f: impl FnOnce(&std::path::Path) -> T) -> T {
+    let root = env::temp_dir().join(format!("aoc-{}-data-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+
+    DATA_ROOT.set(Some(root.clone()));
+    let result = f(&root);
+    DATA_ROOT.set(None);
+
+    fs::remove_dir_all(&root).unwrap();
+    result
+}
+
+/// Like [`get_data_dir`], but creates the directory and its folders first.
+/// Everything that writes data goes through this, so a new year needs no setup.
+pub fn create_data_dir(year: Year) -> io::Result<PathBuf> {
+    let dir = get_data_dir(year);
+    for folder in DATA_FOLDERS {
+        fs::create_dir_all(dir.join(folder))?;
+    }
+    Ok(dir)
+}
+
+/// Name of the binary of a solution, e.g. `2024-06`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(get_data_dir(year))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The input is read from `data/YYYY/inputs`, unless the binary is called with `--input <path>`, `--input -` or `--example [N]`.
+///
+/// Passing `parse = <fn>` splits parsing from solving: the parse function is called with the input and timed on its own,
+/// both parts then receive a reference to the parsed value instead of the raw input.
+///
+/// Alternative implementations of a part can be registered as `variants = [<part> => <fn>, ...]`.
+/// `cargo solve <day> --compare` benches them against the regular part on the same input and checks that all agree on the answer.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// ```ignore
+/// advent_of_code::solution!(2024, 6, parse = parse);
+///
+/// fn parse(input: &str) -> Grid<Entity> { ... }
+/// pub fn part_one(grid: &Grid<Entity>) -> Option<u32> { ... }
+/// ```
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr $(, variants = [$($vpart:literal => $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl $year, $day, [[part_one, 1] [part_two, 2]] [$($([$vfunc, $vpart])*)?]);
+    };
+    ($year:expr, $day:expr, 1 $(, variants = [$($vpart:literal => $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl $year, $day, [[part_one, 1]] [$($([$vfunc, $vpart])*)?]);
+    };
+    ($year:expr, $day:expr, 2 $(, variants = [$($vpart:literal => $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl $year, $day, [[part_two, 2]] [$($([$vfunc, $vpart])*)?]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, parse = $parse:expr $(, variants = [$($vpart:literal => $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl_parsed $year, $day, $parse, [[part_one, 1] [part_two, 2]] [$($([$vfunc, $vpart])*)?]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, parse = $parse:expr, 1 $(, variants = [$($vpart:literal => $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl_parsed $year, $day, $parse, [[part_one, 1]] [$($([$vfunc, $vpart])*)?]);
+    };
+    ($year:expr, $day:expr, parse = $parse:expr, 2 $(, variants = [$($vpart:literal => $vfunc:ident),* $(,)?])?) => {
+        $crate::solution!(@impl_parsed $year, $day, $parse, [[part_two, 2]] [$($([$vfunc, $vpart])*)?]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@impl $year:expr, $day:expr, [$( [$func:ident, $part:expr] )*] [$( [$vfunc:ident, $vpart:expr] )*]) => {
+        $crate::solution!(@header $year, $day);
+
+        pub fn main() {
+            use $crate::template::runner::*;
+            let input = $crate::template::InputSource::from_env().read(YEAR, DAY);
+
+            if $crate::template::variants::is_compare() {
+                $crate::solution!(@compare &input[..], [$( [$func, $part] )* $( [$vfunc, $vpart] )*]);
+                return;
+            }
+
+            $( run_part($func, &input, YEAR, DAY, $part); )*
+        }
+    };
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+    (@impl_parsed $year:expr, $day:expr, $parse:expr, [$( [$func:ident, $part:expr] )*] [$( [$vfunc:ident, $vpart:expr] )*]) => {
+        $crate::solution!(@header $year, $day);
 
-        fn main() {
+        pub fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = $crate::template::InputSource::from_env().read(YEAR, DAY);
+
+            if $crate::template::variants::is_compare() {
+                let parsed = ($parse)(&input);
+                $crate::solution!(@compare &parsed, [$( [$func, $part] )* $( [$vfunc, $vpart] )*]);
+                return;
+            }
+
+            let parsed = run_parse($parse, &input, DAY);
+            $( run_part($func, &parsed, YEAR, DAY, $part); )*
         }
     };
+
+    (@compare $input:expr, [$( [$func:ident, $part:expr] )*]) => {
+        $crate::template::variants::compare(
+            $input,
+            &[$( $crate::template::variants::Variant::new(stringify!($func), $part, $func), )*],
+        )
+    };
+
+    (@header $year:expr, $day:expr) => {
+        /// The year of the event.
+        const YEAR: $crate::template::Year = $crate::year!($year);
+
+        /// The current day.
+        const DAY: $crate::template::Day = $crate::day!($day);
+
+        const _: () = assert!(
+            DAY.into_inner() <= YEAR.last_day(),
+            "the event of this year has fewer days"
+        );
+    };
 }
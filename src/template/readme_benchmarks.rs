@@ -3,9 +3,7 @@
 use std::{fs, io};
 
 use crate::template::timings::Timings;
-use crate::template::Day;
-
-static MARKER: &str = "<!--- benchmarking table --->";
+use crate::template::{Day, Year};
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -21,17 +19,23 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}/{day}.rs")
+}
+
+/// Every year has its own table, delimited by markers like `<!--- 2024 benchmarking table --->`.
+fn marker(year: Year) -> String {
+    format!("<!--- {year} benchmarking table --->")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates a section of the readme that is delimited by two markers.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,129 +56,322 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Formats a byte count with a binary unit, e.g. `1.5 MiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.1} {unit}")
+}
+
+/// Marks rows whose solution changed since it was benched.
+const STALE_MARK: &str = "⚠️";
+
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: Timings,
+    stale: &[Day],
+    total_millis: f64,
+) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+    let marker = marker(year);
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // heap columns are only shown once `cargo time --memory` stored some.
+    let has_heap = timings.data.iter().any(|t| t.heap.is_some());
+
+    let mut lines: Vec<String> = vec![marker.clone(), header, String::new()];
+
+    // NOTE: numbers from different machines are not comparable, so the environment is always stated.
+    if let Some(fingerprint) = &timings.fingerprint {
+        lines.push(format!("_Measured on {fingerprint}._"));
+        lines.push(String::new());
+    }
+
+    lines.push(format!("![Benchmark chart](./data/{year}/benchmarks.svg)"));
+    lines.push(String::new());
+
+    if has_heap {
+        lines.push("| Day | Parse | Part 1 | Part 2 | Allocated | Peak | Allocations |".into());
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let path = get_path_for_bin(year, timing.day);
+        let missing = if timing.timed_out { "timed out" } else { "-" };
+        let mark = if stale.contains(&timing.day) {
+            format!(" {STALE_MARK}")
+        } else {
+            String::new()
+        };
+        let mut line = format!(
+            "| [Day {}]({}){} | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            mark,
+            timing.parse.map_or("-".into(), |p| p.to_string()),
+            timing.part_1.map_or(missing.into(), |p| p.to_string()),
+            timing.part_2.map_or(missing.into(), |p| p.to_string())
+        );
+
+        if has_heap {
+            line.push_str(&match timing.heap {
+                Some(heap) => format!(
+                    " `{}` | `{}` | `{}` |",
+                    format_bytes(heap.total_bytes),
+                    format_bytes(heap.max_bytes),
+                    heap.total_blocks
+                ),
+                None => " `-` | `-` | `-` |".into(),
+            });
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+
+    if !stale.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            "{STALE_MARK} The solution changed since it was benched, run `cargo time` to update."
+        ));
+    }
+
+    lines.push(marker);
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    stale: &[Day],
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &marker(year))?;
+    let table = construct_table("##", year, timings, stale, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let stale = timings.stale_days(year);
+    update_content(&mut readme, year, timings, &stale, total_millis)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{format_bytes, marker, update_content};
+    use crate::{
+        day,
+        template::fingerprint::Fingerprint,
+        template::stats::HeapUsage,
+        template::timings::{PhaseTiming, Timing, Timings},
+        template::Year,
+        year,
+    };
+
+    const YEAR: Year = year!(2024);
+
+    fn phase(millis: u64) -> PhaseTiming {
+        PhaseTiming {
+            nanos: millis * 1_000_000,
+            samples: Some(10),
+            stats: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(phase(10)),
+                    part_2: Some(phase(20)),
                     total_nanos: 3e+10,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(phase(30)),
+                    part_2: Some(phase(40)),
                     total_nanos: 7e+10,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: Some(phase(5)),
+                    part_1: Some(phase(40)),
+                    part_2: Some(phase(50)),
                     total_nanos: 9e+10,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 },
             ],
+            fingerprint: None,
         }
     }
 
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn format_benchmarks_with_heap() {
+        let mut timings = get_mock_timings();
+        timings.data[0].heap = Some(HeapUsage {
+            total_bytes: 2048,
+            max_bytes: 1024,
+            total_blocks: 12,
+        });
+
+        let mut s = format!("{}\n{}", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, timings, &[], 190.0).unwrap();
+
+        assert_eq!(
+            s.contains("| Day | Parse | Part 1 | Part 2 | Allocated | Peak | Allocations |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/2024/01.rs) | `-` | `10.0ms` | `20.0ms` | `2.0 KiB` | `1.0 KiB` | `12` |"),
+            true
+        );
+        assert_eq!(
+            s.contains(
+                "| [Day 2](./src/bin/2024/02.rs) | `-` | `30.0ms` | `40.0ms` | `-` | `-` | `-` |"
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn marks_stale_days() {
+        let mut s = format!("{}\n{}", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), &[day!(2)], 190.0).unwrap();
+
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/2024/01.rs) | `-` | `10.0ms` | `20.0ms` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/2024/02.rs) ⚠️ | `-` | `30.0ms` | `40.0ms` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("⚠️ The solution changed since it was benched, run `cargo time` to update."),
+            true
+        );
+    }
+
+    #[test]
+    fn states_the_environment() {
+        let mut timings = get_mock_timings();
+        timings.fingerprint = Some(Fingerprint {
+            cpu: Some("Apple M2".into()),
+            cores: Some(8),
+            os: "macos aarch64".into(),
+            rustc: None,
+            profile: "release".into(),
+        });
+
+        let mut s = format!("{}\n{}", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, timings, &[], 190.0).unwrap();
+
+        assert_eq!(
+            s.contains("## 2024 Benchmarks\n\n_Measured on Apple M2 (8 cores), macos aarch64, unknown rustc, release profile._\n\n![Benchmark chart]"),
+            true
+        );
+    }
+
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), &[], 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("{} {} {}", marker(YEAR), marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), &[], 190.0).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), &[], 190.0).unwrap();
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), &[], 190.0).unwrap();
+        update_content(&mut s, YEAR, get_mock_timings(), &[], 190.0).unwrap();
+        assert_eq!(s.matches(&marker(YEAR)).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker(YEAR), marker(YEAR));
+        update_content(&mut s, YEAR, get_mock_timings(), &[], 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- 2024 benchmarking table --->",
+            "## 2024 Benchmarks",
+            "",
+            "![Benchmark chart](./data/2024/benchmarks.svg)",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024/01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024/02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024/04.rs) | `5.0ms` | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- 2024 benchmarking table --->",
             "baz",
         ]
         .join("\n");
@@ -0,0 +1,177 @@
+/// Module that updates the readme with the star progress of each day.
+/// A part counts as solved once its answer is known, i.e. it was submitted successfully or accepted.
+use std::fs;
+
+use crate::template::{
+    answers::Answers,
+    aoc_client::get_puzzle_path,
+    readme_benchmarks::{get_path_for_bin, locate_table, Error},
+    Day, Year,
+};
+
+/// Progress of a single scaffolded day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: Day,
+    /// Puzzle title, if the puzzle description was downloaded.
+    pub title: Option<String>,
+    pub stars: u8,
+}
+
+impl DayProgress {
+    fn read(year: Year, day: Day) -> Self {
+        let answers = Answers::read(year, day);
+
+        DayProgress {
+            day,
+            title: fs::read_to_string(get_puzzle_path(year, day))
+                .ok()
+                .and_then(|puzzle| parse_title(&puzzle)),
+            stars: u8::from(answers.part_1.is_some()) + u8::from(answers.part_2.is_some()),
+        }
+    }
+}
+
+/// Extracts the title from a puzzle description, e.g. `Guard Gallivant` from `--- Day 6: Guard Gallivant ---`.
+fn parse_title(puzzle: &str) -> Option<String> {
+    puzzle.lines().find_map(|line| {
+        let (_, title) = line.split_once("--- Day ")?.1.split_once(": ")?;
+        let title = title.trim().trim_end_matches('-').trim();
+        (!title.is_empty()).then(|| title.to_string())
+    })
+}
+
+/// Every year has its own table, delimited by markers like `<!--- 2024 stars table --->`.
+fn marker(year: Year) -> String {
+    format!("<!--- {year} stars table --->")
+}
+
+fn format_stars(stars: u8) -> &'static str {
+    match stars {
+        0 => "-",
+        1 => "⭐",
+        _ => "⭐⭐",
+    }
+}
+
+fn construct_table(prefix: &str, year: Year, progress: &[DayProgress]) -> String {
+    let header = format!("{prefix} {year} Progress");
+    let marker = marker(year);
+
+    let mut lines: Vec<String> = vec![
+        marker.clone(),
+        header,
+        String::new(),
+        "| Day | Puzzle | Stars | Solution |".into(),
+        "| :---: | :--- | :---: | :---: |".into(),
+    ];
+
+    for day in progress {
+        let title = day
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Day {}", day.day.into_inner()));
+        let puzzle = format!(
+            "[{title}](https://adventofcode.com/{year}/day/{})",
+            day.day.into_inner()
+        );
+
+        lines.push(format!(
+            "| {} | {} | {} | [{}.rs]({}) |",
+            day.day.into_inner(),
+            puzzle,
+            format_stars(day.stars),
+            day.day,
+            get_path_for_bin(year, day.day)
+        ));
+    }
+
+    let total: u32 = progress.iter().map(|d| u32::from(d.stars)).sum();
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {total} ⭐**"));
+    lines.push(marker);
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, year: Year, progress: &[DayProgress]) -> Result<(), Error> {
+    let positions = locate_table(s, &marker(year))?;
+    let table = construct_table("##", year, progress);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Rewrites the progress table for all scaffolded days of a year.
+pub fn update(year: Year) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    let progress: Vec<DayProgress> = year
+        .days()
+        .filter(|day| fs::metadata(get_path_for_bin(year, *day)).is_ok())
+        .map(|day| DayProgress::read(year, day))
+        .collect();
+
+    update_content(&mut readme, year, &progress)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{marker, parse_title, update_content, DayProgress};
+    use crate::{day, year};
+
+    fn get_mock_progress() -> Vec<DayProgress> {
+        vec![
+            DayProgress {
+                day: day!(1),
+                title: Some("Historian Hysteria".into()),
+                stars: 2,
+            },
+            DayProgress {
+                day: day!(6),
+                title: None,
+                stars: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn parses_titles() {
+        let puzzle = "\\--- Day 6: Guard Gallivant ---\n----------\n\nThe Historians...";
+        assert_eq!(parse_title(puzzle), Some("Guard Gallivant".into()));
+        assert_eq!(parse_title("no title"), None);
+    }
+
+    #[test]
+    fn format_progress() {
+        let marker = marker(year!(2024));
+        let mut s = format!("foo\n{marker}\n{marker}\nbar");
+        update_content(&mut s, year!(2024), &get_mock_progress()).unwrap();
+        let expected = [
+            "foo",
+            "<!--- 2024 stars table --->",
+            "## 2024 Progress",
+            "",
+            "| Day | Puzzle | Stars | Solution |",
+            "| :---: | :--- | :---: | :---: |",
+            "| 1 | [Historian Hysteria](https://adventofcode.com/2024/day/1) | ⭐⭐ | [01.rs](./src/bin/2024/01.rs) |",
+            "| 6 | [Day 6](https://adventofcode.com/2024/day/6) | ⭐ | [06.rs](./src/bin/2024/06.rs) |",
+            "",
+            "**Total: 3 ⭐**",
+            "<!--- 2024 stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, year!(2024), &get_mock_progress()).unwrap();
+    }
+}
@@ -0,0 +1,271 @@
+/// Machine-readable results that solution binaries emit next to their human output.
+/// Each timed phase of a solution is written as one JSON object per line to the file passed via `--records <path>`.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    stats::{HeapUsage, Stats},
+    Day,
+};
+
+/// The command-line flag that tells a solution binary where to write its records.
+pub const RECORDS_FLAG: &str = "--records";
+
+/// A timed section of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+/// Outcome of a single phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The solution was killed before finishing this phase.
+    TimedOut,
+}
+
+/// The result of running a single phase of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub day: Day,
+    pub phase: Phase,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub stats: Stats,
+    /// Only present if the solution was built with the `dhat-heap` feature.
+    pub heap: Option<HeapUsage>,
+}
+
+impl Phase {
+    /// Human-readable name of the phase, as printed by the runner.
+    pub fn label(self) -> String {
+        match self {
+            Phase::Parse => "Parse".into(),
+            Phase::Part(part) => format!("Part {part}"),
+        }
+    }
+}
+
+impl Record {
+    /// Appends this record to the records file, if one was passed on the command line.
+    pub fn write(&self) -> Result<(), io::Error> {
+        let args: Vec<String> = env::args().collect();
+
+        let Some(path) = args
+            .iter()
+            .position(|x| x == RECORDS_FLAG)
+            .and_then(|i| args.get(i + 1))
+        else {
+            return Ok(());
+        };
+
+        let json = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{json}")
+    }
+}
+
+/// Reads all records from a records file. A missing file yields no records.
+pub fn read_file(path: &Path) -> Result<Vec<Record>, String> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("record is not valid JSON."))?;
+            Record::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part_{part}"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            s => s
+                .strip_prefix("part_")
+                .and_then(|part| part.parse().ok())
+                .map(Phase::Part)
+                .ok_or(format!("unknown phase `{s}`.")),
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::TimedOut => write!(f, "timed_out"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "timed_out" => Ok(Status::TimedOut),
+            s => Err(format!("unknown status `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Record> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("phase".into(), JsonValue::String(value.phase.to_string()));
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.stats.median.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(value.stats));
+        map.insert(
+            "heap".into(),
+            value.heap.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Record {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected record.{key} to be a string."))
+        };
+
+        let day = Day::from_str(string("day")?).or(Err("Expected record.day to be a Day."))?;
+        let phase = Phase::from_str(string("phase")?)?;
+        let status = Status::from_str(string("status")?)?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let stats = Stats::try_from(json.get("stats").ok_or("Expected record.stats.")?)?;
+
+        let heap = match json.get("heap") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(HeapUsage::try_from(v)?),
+        };
+
+        Ok(Record {
+            day,
+            phase,
+            status,
+            answer: answer.cloned(),
+            stats,
+            heap,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{Phase, Record, Status};
+    use crate::{
+        day,
+        template::stats::{HeapUsage, Stats},
+    };
+
+    #[test]
+    fn roundtrips_records() {
+        let record = Record {
+            day: day!(6),
+            phase: Phase::Part(2),
+            status: Status::Solved,
+            answer: Some("Part 1: (2s @ 5 samples)\n✖".into()),
+            stats: Stats::single(Duration::from_micros(42)),
+            heap: Some(HeapUsage {
+                total_bytes: 1024,
+                max_bytes: 512,
+                total_blocks: 3,
+            }),
+        };
+
+        let json = JsonValue::from(&record).stringify().unwrap();
+        let parsed = Record::try_from(&JsonValue::from_str(&json).unwrap()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn parses_phases() {
+        assert_eq!(Phase::from_str("parse"), Ok(Phase::Parse));
+        assert_eq!(Phase::from_str("part_1"), Ok(Phase::Part(1)));
+        assert_eq!(Phase::from_str("part_x").is_err(), true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_records() {
+        let json = r#"{ "day": "01", "phase": "part_1", "status": "solved" }"#;
+        Record::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+/// Runs multiple solutions inside of a single process.
+/// The `registry` binary links the `main` function of every solution, [`run_multi`](super::run_multi) invokes it once for all requested days.
+use std::{env, panic};
+
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_RESET};
+
+/// The name of the binary that links all solutions.
+pub const REGISTRY_BIN: &str = "registry";
+
+/// The command-line flag that selects the year of the days to run, e.g. `--year 2024`.
+pub const YEAR_FLAG: &str = "--year";
+
+/// The command-line flag that selects the days to run, e.g. `--days 01,06`.
+pub const DAYS_FLAG: &str = "--days";
+
+/// Runs the days passed via `--days` in order, with the same output as running their binaries one by one.
+/// A panicking solution does not abort the days that follow it.
+pub fn run(solutions: &[(Year, Day, fn())]) {
+    let args = env::args().collect::<Vec<_>>();
+    let Some(year) = parse_year(&args) else {
+        eprintln!("Missing or invalid `{YEAR_FLAG}`.");
+        return;
+    };
+
+    for (i, day) in parse_days(&args).iter().enumerate() {
+        print_header(*day, i > 0);
+
+        match solutions.iter().find(|(y, d, _)| *y == year && d == day) {
+            Some((_, _, solution)) => {
+                // the panic message is printed by the default hook.
+                let _ = panic::catch_unwind(solution);
+            }
+            None => println!("Not solved."),
+        }
+    }
+}
+
+/// Prints the heading of a day when running multiple days.
+pub fn print_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Arguments that select the given days of a year.
+pub fn days_args(year: Year, days: &[Day]) -> Vec<String> {
+    let days = days
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    vec![YEAR_FLAG.into(), year.to_string(), DAYS_FLAG.into(), days]
+}
+
+fn parse_year(args: &[String]) -> Option<Year> {
+    args.iter()
+        .position(|x| x == YEAR_FLAG)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|year| year.parse().ok())
+}
+
+fn parse_days(args: &[String]) -> Vec<Day> {
+    args.iter()
+        .position(|x| x == DAYS_FLAG)
+        .and_then(|i| args.get(i + 1))
+        .map(|days| days.split(',').filter_map(|d| d.parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{days_args, parse_days, parse_year};
+    use crate::{day, year};
+
+    #[test]
+    fn roundtrips_days() {
+        let mut args = vec!["registry".to_string(), "--time".to_string()];
+        args.extend(days_args(year!(2024), &[day!(1), day!(6), day!(23)]));
+        assert_eq!(parse_year(&args), Some(year!(2024)));
+        assert_eq!(parse_days(&args), vec![day!(1), day!(6), day!(23)]);
+    }
+
+    #[test]
+    fn handles_missing_days() {
+        assert!(parse_days(&["registry".to_string()]).is_empty());
+        assert_eq!(parse_year(&["registry".to_string()]), None);
+    }
+}
@@ -0,0 +1,223 @@
+/// Exports stored timings in formats other tools can consume.
+use std::{cmp::Reverse, fmt::Write, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    timings::{PhaseTiming, Timing, Timings},
+    Day,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportSort {
+    #[default]
+    Day,
+    /// Descending by the slowest phase of each day.
+    Slowest,
+}
+
+/// Selects and orders the timings that go into a report. An empty `days` selects all days.
+pub fn select(timings: &Timings, days: &[Day], sort: ReportSort) -> Timings {
+    let mut data: Vec<Timing> = timings
+        .data
+        .iter()
+        .filter(|t| days.is_empty() || days.contains(&t.day))
+        .cloned()
+        .collect();
+
+    match sort {
+        ReportSort::Day => data.sort_by_key(|t| t.day),
+        ReportSort::Slowest => data.sort_by_key(|t| (Reverse(slowest_nanos(t)), t.day)),
+    }
+
+    Timings {
+        data,
+        fingerprint: timings.fingerprint.clone(),
+    }
+}
+
+pub fn render(timings: &Timings, format: ReportFormat) -> Result<String, String> {
+    match format {
+        ReportFormat::Csv => Ok(render_csv(timings)),
+        ReportFormat::Json => JsonValue::from(timings.clone())
+            .format()
+            .map_err(|e| e.to_string()),
+        ReportFormat::Markdown => Ok(render_markdown(timings)),
+    }
+}
+
+fn slowest_nanos(timing: &Timing) -> u64 {
+    [timing.parse, timing.part_1, timing.part_2]
+        .into_iter()
+        .flatten()
+        .map(|p| p.nanos)
+        .max()
+        .unwrap_or(0)
+}
+
+fn render_csv(timings: &Timings) -> String {
+    let mut csv = String::from(
+        "day,parse_nanos,part_1_nanos,part_2_nanos,parse_samples,part_1_samples,part_2_samples,total_nanos,timed_out\n",
+    );
+
+    let nanos = |p: Option<PhaseTiming>| p.map(|p| p.nanos.to_string()).unwrap_or_default();
+    let samples = |p: Option<PhaseTiming>| {
+        p.and_then(|p| p.samples)
+            .map(|s| s.to_string())
+            .unwrap_or_default()
+    };
+
+    for t in &timings.data {
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{},{},{}",
+            t.day,
+            nanos(t.parse),
+            nanos(t.part_1),
+            nanos(t.part_2),
+            samples(t.parse),
+            samples(t.part_1),
+            samples(t.part_2),
+            t.total_nanos,
+            t.timed_out
+        );
+    }
+
+    csv
+}
+
+fn render_markdown(timings: &Timings) -> String {
+    let mut md = String::from("# Benchmarks\n\n");
+
+    if let Some(fingerprint) = &timings.fingerprint {
+        let _ = write!(md, "_Measured on {fingerprint}._\n\n");
+    }
+
+    md.push_str(
+        "| Day | Parse | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: | :---: |\n",
+    );
+
+    let phase = |p: Option<PhaseTiming>| p.map_or("-".into(), |p| p.to_string());
+
+    for t in &timings.data {
+        let _ = writeln!(
+            md,
+            "| {} | `{}` | `{}` | `{}` | `{:.2}ms` |",
+            t.day.into_inner(),
+            phase(t.parse),
+            phase(t.part_1),
+            phase(t.part_2),
+            t.total_nanos / 1_000_000_f64
+        );
+    }
+
+    let _ = write!(md, "\n**Total: {:.2}ms**\n", timings.total_millis());
+    md
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            "md" | "markdown" => Ok(ReportFormat::Markdown),
+            s => Err(format!(
+                "unknown format `{s}`, expected `csv`, `json` or `markdown`."
+            )),
+        }
+    }
+}
+
+impl FromStr for ReportSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(ReportSort::Day),
+            "slowest" => Ok(ReportSort::Slowest),
+            s => Err(format!("unknown sort `{s}`, expected `day` or `slowest`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, select, ReportFormat, ReportSort};
+    use crate::{
+        day,
+        template::timings::{PhaseTiming, Timing, Timings},
+    };
+
+    fn phase(micros: u64) -> Option<PhaseTiming> {
+        Some(PhaseTiming {
+            nanos: micros * 1000,
+            samples: Some(10),
+            stats: None,
+        })
+    }
+
+    fn get_mock_timings() -> Timings {
+        let timing = |day, part_1, part_2| Timing {
+            day,
+            parse: None,
+            part_1,
+            part_2,
+            total_nanos: 0.0,
+            timed_out: false,
+            heap: None,
+            source_hash: None,
+        };
+
+        Timings {
+            data: vec![
+                timing(day!(1), phase(10), phase(20)),
+                timing(day!(2), phase(500), None),
+                timing(day!(3), phase(5), phase(90)),
+            ],
+            fingerprint: None,
+        }
+    }
+
+    #[test]
+    fn filters_days() {
+        let report = select(&get_mock_timings(), &[day!(3), day!(1)], ReportSort::Day);
+        let days: Vec<_> = report.data.iter().map(|t| t.day).collect();
+        assert_eq!(days, vec![day!(1), day!(3)]);
+    }
+
+    #[test]
+    fn sorts_by_slowest_part() {
+        let report = select(&get_mock_timings(), &[], ReportSort::Slowest);
+        let days: Vec<_> = report.data.iter().map(|t| t.day).collect();
+        assert_eq!(days, vec![day!(2), day!(3), day!(1)]);
+    }
+
+    #[test]
+    fn renders_csv() {
+        let csv = render(&get_mock_timings(), ReportFormat::Csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("day,parse_nanos,part_1_nanos"));
+        assert_eq!(lines[2], "02,,500000,,,10,,0,false");
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let md = render(&get_mock_timings(), ReportFormat::Markdown).unwrap();
+        assert!(md.starts_with("# Benchmarks"));
+        assert!(md.contains("| 2 | `-` | `500.0µs` | `-` | `0.00ms` |"));
+    }
+}
@@ -1,41 +1,124 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs, io,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    registry, runner::BenchConfig, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
-    all_days,
+    answers::{Answers, Verdict},
+    fingerprint::Fingerprint,
+    record::{Phase, Record},
+    source_hash,
+    stats::HeapUsage,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Wall-clock limits for running a solution. A solution that exceeds either limit is killed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timeouts {
+    /// Limit for a whole day, including the parse step of the solution.
+    pub day: Option<Duration>,
+    /// Limit for a single phase of a day, i.e. the parse step or a part.
+    pub part: Option<Duration>,
+}
+
+impl Timeouts {
+    fn is_set(&self) -> bool {
+        self.day.is_some() || self.part.is_some()
+    }
+
+    /// Parses a timeout in seconds, e.g. `2.5`. Only positive, finite values are valid.
+    pub fn parse_secs(s: &str) -> Result<Duration, String> {
+        let secs: f64 = s
+            .parse()
+            .map_err(|_| format!("`{s}` is not a number of seconds."))?;
+
+        if secs <= 0.0 {
+            return Err(format!("timeouts must be positive, got `{s}`."));
+        }
+
+        Duration::try_from_secs_f64(secs).map_err(|_| format!("`{s}` is not a valid timeout."))
+    }
+}
 
-    let mut need_space = false;
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_memory: bool,
+    jobs: usize,
+    bench_config: &BenchConfig,
+    timeouts: &Timeouts,
+) -> Option<Timings> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut checks = AnswerChecks::default();
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = year
+        .days()
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        .collect();
+
+    // hashed before building, so the hashes describe the code that is measured.
+    let source_hashes: HashMap<Day, String> = days
+        .iter()
+        .filter_map(|&day| Some((day, source_hash::hash_solution(year, day)?)))
+        .collect();
+
+    // a timed out or profiled solution has to be killed or built on its own, everything else runs in-process.
+    // NOTE: heap usage is measured and printed one day at a time, so it does not run in parallel.
+    let results = if jobs > 1 && !is_memory {
+        run_parallel(
+            year,
+            &days,
+            jobs,
+            is_timed,
+            is_release,
+            bench_config,
+            timeouts,
+        )
+    } else if timeouts.is_set() || is_memory {
+        run_isolated(
+            year,
+            &days,
+            is_timed,
+            is_release,
+            is_memory,
+            bench_config,
+            timeouts,
+        )
+    } else {
+        child_commands::run_registry(year, &days, is_timed, is_release, bench_config).unwrap()
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    for result in results {
+        if let Some(records) = result.records {
+            checks.add(result.day, &records, &Answers::read(year, result.day));
+            let mut timing = child_commands::collect_timing(&records, result.day);
+            timing.heap = result.heap;
+            timing.source_hash = source_hashes.get(&result.day).cloned();
+            timings.push(timing);
+        }
+    }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    checks.print_summary();
 
     if is_timed {
-        let timings = Timings { data: timings };
+        let timings = Timings {
+            data: timings,
+            fingerprint: Some(Fingerprint::capture(is_release)),
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
@@ -46,6 +129,207 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// The outcome of running a single day.
+pub struct DayRun {
+    pub day: Day,
+    /// `None` if the day has not been scaffolded yet.
+    pub records: Option<Vec<Record>>,
+    pub heap: Option<HeapUsage>,
+}
+
+/// Runs each day in its own solution binary. Slower than the registry, but a day can be killed or profiled on its own.
+fn run_isolated(
+    year: Year,
+    days: &[Day],
+    is_timed: bool,
+    is_release: bool,
+    is_memory: bool,
+    bench_config: &BenchConfig,
+    timeouts: &Timeouts,
+) -> Vec<DayRun> {
+    days.iter()
+        .enumerate()
+        .map(|(i, &day)| {
+            registry::print_header(day, i > 0);
+
+            let records = child_commands::run_solution(
+                year,
+                day,
+                is_timed,
+                is_release,
+                false,
+                None,
+                bench_config,
+                timeouts,
+            )
+            .unwrap();
+
+            let heap = match records {
+                Some(_) if is_memory => {
+                    child_commands::measure_heap(year, day, bench_config, timeouts)
+                }
+                Some(_) => None,
+                None => {
+                    println!("Not solved.");
+                    None
+                }
+            };
+
+            DayRun { day, records, heap }
+        })
+        .collect()
+}
+
+/// Runs up to `jobs` solution binaries at once. The output of each day is buffered and printed in day order,
+/// as soon as the day and all days before it have finished.
+fn run_parallel(
+    year: Year,
+    days: &[Day],
+    jobs: usize,
+    is_timed: bool,
+    is_release: bool,
+    bench_config: &BenchConfig,
+    timeouts: &Timeouts,
+) -> Vec<DayRun> {
+    // build up front, concurrent builds of the same package would only wait for each other.
+    child_commands::build_solutions(is_release).unwrap();
+
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next_day = &next_day;
+
+            scope.spawn(move || loop {
+                let i = next_day.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(i) else {
+                    break;
+                };
+
+                let output_path = child_commands::get_output_path(day);
+                let _ = fs::remove_file(&output_path);
+
+                let (records, output) = match child_commands::run_solution(
+                    year,
+                    day,
+                    is_timed,
+                    is_release,
+                    false,
+                    Some(&output_path),
+                    bench_config,
+                    timeouts,
+                ) {
+                    Ok(Some(records)) => (
+                        Some(records),
+                        fs::read_to_string(&output_path).unwrap_or_default(),
+                    ),
+                    Ok(None) => (None, "Not solved.\n".into()),
+                    // the other days keep running, this one is reported like a day without result.
+                    Err(e) => (None, format!("Failed to run day {day}: {e:?}\n")),
+                };
+                let _ = fs::remove_file(&output_path);
+
+                let result = DayRun {
+                    day,
+                    records,
+                    heap: None,
+                };
+                let _ = sender.send((i, result, output));
+            });
+        }
+
+        drop(sender);
+
+        // NOTE: results arrive in any order, print them in order of their days.
+        let mut pending = BTreeMap::new();
+        let mut results = Vec::with_capacity(days.len());
+
+        for (i, result, output) in receiver {
+            pending.insert(i, (result, output));
+
+            while let Some((result, output)) = pending.remove(&results.len()) {
+                registry::print_header(result.day, !results.is_empty());
+                print!("{output}");
+                results.push(result);
+            }
+        }
+
+        results
+    })
+}
+
+/// A part whose result no longer matches its known answer.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub day: Day,
+    pub part: u8,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+/// Tally of results compared against the known answers.
+#[derive(Debug, Default)]
+pub struct AnswerChecks {
+    pub correct: usize,
+    pub regressions: Vec<Regression>,
+}
+
+impl AnswerChecks {
+    /// Compares the results of a day's parts with its known answers.
+    /// A part without a result counts as a regression if its answer is known.
+    pub fn add(&mut self, day: Day, records: &[Record], answers: &Answers) {
+        for part in [1, 2] {
+            let Some(expected) = answers.get(part) else {
+                continue;
+            };
+
+            let actual = records
+                .iter()
+                .find(|r| r.day == day && r.phase == Phase::Part(part))
+                .and_then(|r| r.answer.clone());
+
+            let verdict = actual
+                .as_ref()
+                .and_then(|actual| answers.check(part, actual));
+
+            if verdict == Some(Verdict::Correct) {
+                self.correct += 1;
+            } else {
+                self.regressions.push(Regression {
+                    day,
+                    part,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+
+    fn print_summary(&self) {
+        if self.correct == 0 && self.regressions.is_empty() {
+            return;
+        }
+
+        println!(
+            "\n{ANSI_BOLD}Known answers:{ANSI_RESET} {} correct, {} regressed",
+            self.correct,
+            self.regressions.len()
+        );
+
+        for regression in &self.regressions {
+            println!(
+                "  ✘ Day {} part {}: expected `{}`, got `{}`",
+                regression.day,
+                regression.part,
+                regression.expected,
+                regression.actual.as_deref().unwrap_or("-")
+            );
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -60,133 +344,459 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}/{day}.rs")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{AnswerChecks, Timeouts};
+    use crate::{
+        day,
+        template::{
+            answers::Answers,
+            record::{Phase, Record, Status},
+            stats::Stats,
+        },
+    };
+
+    fn record(part: u8, answer: Option<&str>) -> Record {
+        Record {
+            day: day!(6),
+            phase: Phase::Part(part),
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::TimedOut
+            },
+            answer: answer.map(String::from),
+            stats: Stats::single(Duration::from_millis(1)),
+            heap: None,
+        }
+    }
+
+    #[test]
+    fn counts_correct_answers() {
+        let mut checks = AnswerChecks::default();
+        let answers = Answers {
+            part_1: Some("41".into()),
+            part_2: None,
+        };
+        checks.add(
+            day!(6),
+            &[record(1, Some("41")), record(2, Some("6"))],
+            &answers,
+        );
+        assert_eq!(checks.correct, 1);
+        assert_eq!(checks.regressions.len(), 0);
+    }
+
+    #[test]
+    fn reports_regressions() {
+        let mut checks = AnswerChecks::default();
+        let answers = Answers {
+            part_1: Some("41".into()),
+            part_2: Some("6".into()),
+        };
+        checks.add(day!(6), &[record(1, Some("40")), record(2, None)], &answers);
+        assert_eq!(checks.correct, 0);
+        assert_eq!(checks.regressions.len(), 2);
+        assert_eq!(checks.regressions[0].actual, Some("40".into()));
+        assert_eq!(checks.regressions[1].actual, None);
+    }
+
+    #[test]
+    fn parses_timeouts() {
+        assert_eq!(Timeouts::parse_secs("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(Timeouts::parse_secs("-1").is_err());
+        assert!(Timeouts::parse_secs("0").is_err());
+        assert!(Timeouts::parse_secs("NaN").is_err());
+        assert!(Timeouts::parse_secs("inf").is_err());
+        assert!(Timeouts::parse_secs("1e300").is_err());
+        assert!(Timeouts::parse_secs("soon").is_err());
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{get_path_for_bin, DayRun, Error, Timeouts};
+    use crate::template::{
+        get_bin_name,
+        readme_benchmarks::format_bytes,
+        record::{self, Phase, Record, Status, RECORDS_FLAG},
+        registry::{self, REGISTRY_BIN},
+        runner::BenchConfig,
+        stats::{HeapUsage, Stats},
+        timings::PhaseTiming,
+        Day, Year,
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        env,
+        fmt::Display,
+        fs::{self, File, OpenOptions},
+        io::{self, Write},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, Stdio},
         thread,
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Interval at which a running solution is checked for progress.
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    /// Run the solution bin for a given day.
+    /// Returns `None` if the day has not been scaffolded yet, otherwise the records emitted by the solution.
+    ///
+    /// With `with_heap`, the solution is built with the `dhat` profile and its output is discarded,
+    /// only the heap usage in its records is of interest then.
+    /// With an `output` path, the output is written to that file instead of being forwarded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        with_heap: bool,
+        output: Option<&Path>,
+        bench_config: &BenchConfig,
+        timeouts: &Timeouts,
+    ) -> Result<Option<Vec<Record>>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(None);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let profile_args = get_profile_args(is_release, with_heap);
 
-        if is_release {
-            args.push("--release");
+        // build up front so that compilation does not count towards the timeouts.
+        if timeouts.is_set() {
+            build_solution(year, day, &profile_args)?;
         }
 
+        let records_path = get_records_path(day);
+        // a stale file from an aborted run would otherwise be appended to.
+        let _ = fs::remove_file(&records_path);
+
+        let bin_name = get_bin_name(year, day);
+        let records_path_str = records_path.to_string_lossy();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
+        args.extend(&profile_args);
+
+        args.push("--");
+        args.push(RECORDS_FLAG);
+        args.push(&records_path_str);
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // human-readable output is forwarded as-is, results are read from the records file.
+        let output_file = output.map(open_output).transpose()?;
+        let stdio = || -> Result<Stdio, Error> {
+            Ok(match &output_file {
+                _ if with_heap => Stdio::null(),
+                Some(file) => Stdio::from(file.try_clone()?),
+                None => Stdio::inherit(),
+            })
+        };
 
         let mut cmd = Command::new("cargo")
             .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
+            .envs(bench_config.envs())
+            .stdout(stdio()?)
+            .stderr(stdio()?)
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let timed_out = wait_with_timeouts(&mut cmd, &records_path, timeouts)?;
 
-        let mut output = vec![];
+        let records = record::read_file(&records_path);
+        let _ = fs::remove_file(&records_path);
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
+        let mut records = records.unwrap_or_else(|e| {
+            eprintln!("Could not read records of day {day}: {e}");
+            vec![]
+        });
+
+        if let Some(elapsed) = timed_out {
+            let mut report: Box<dyn Write> = match output_file {
+                Some(file) => Box::new(file),
+                None => Box::new(io::stdout()),
+            };
+            records.extend(timed_out_records(&records, day, elapsed, &mut report));
+        }
+
+        Ok(Some(records))
+    }
+
+    /// Run all given days in-process through the registry binary.
+    /// Output is forwarded as-is, the records of all days are collected from a single records file.
+    pub fn run_registry(
+        year: Year,
+        days: &[Day],
+        is_timed: bool,
+        is_release: bool,
+        bench_config: &BenchConfig,
+    ) -> Result<Vec<DayRun>, Error> {
+        let records_path = get_records_path(REGISTRY_BIN);
+        let _ = fs::remove_file(&records_path);
+
+        let records_path_str = records_path.to_string_lossy();
+        let days_args = registry::days_args(year, days);
+
+        let mut args = vec!["run", "--quiet", "--bin", REGISTRY_BIN];
+        args.extend(get_profile_args(is_release, false));
+
+        args.push("--");
+        args.push(RECORDS_FLAG);
+        args.push(&records_path_str);
+        args.extend(days_args.iter().map(String::as_str));
+
+        if is_timed {
+            args.push("--time");
+        }
+
+        Command::new("cargo")
+            .args(&args)
+            .envs(bench_config.envs())
+            .status()?;
+
+        let records = record::read_file(&records_path);
+        let _ = fs::remove_file(&records_path);
+
+        let records = records.unwrap_or_else(|e| {
+            eprintln!("Could not read records: {e}");
+            vec![]
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        Ok(days
+            .iter()
+            .map(|&day| DayRun {
+                day,
+                records: Path::new(&get_path_for_bin(year, day))
+                    .exists()
+                    .then(|| records.iter().filter(|r| r.day == day).cloned().collect()),
+                heap: None,
+            })
+            .collect())
+    }
+
+    /// Runs a solution once more under `dhat` and reports the heap usage of all its phases.
+    pub fn measure_heap(
+        year: Year,
+        day: Day,
+        bench_config: &BenchConfig,
+        timeouts: &Timeouts,
+    ) -> Option<HeapUsage> {
+        let records = match run_solution(year, day, false, true, true, None, bench_config, timeouts)
+        {
+            Ok(records) => records?,
+            Err(e) => {
+                eprintln!("Could not measure heap usage of day {day}: {e:?}");
+                return None;
+            }
+        };
+
+        let heap = collect_timing(&records, day).heap;
+
+        match heap {
+            Some(heap) => println!(
+                "Heap: {} allocated in {} blocks, {} peak",
+                format_bytes(heap.total_bytes),
+                heap.total_blocks,
+                format_bytes(heap.max_bytes)
+            ),
+            None => eprintln!("Could not measure heap usage of day {day}."),
+        }
+
+        heap
+    }
+
+    fn get_profile_args(is_release: bool, with_heap: bool) -> Vec<&'static str> {
+        if with_heap {
+            vec!["--profile", "dhat", "--features", "dhat-heap"]
+        } else if is_release {
+            vec!["--release"]
+        } else {
+            vec![]
+        }
+    }
+
+    /// Builds all solution binaries at once.
+    pub fn build_solutions(is_release: bool) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
+        args.extend(get_profile_args(is_release, false));
+
+        Command::new("cargo").args(&args).status()?;
+        Ok(())
+    }
+
+    fn build_solution(year: Year, day: Day, profile_args: &[&str]) -> Result<(), Error> {
+        let bin_name = get_bin_name(year, day);
+        let mut args = vec!["build", "--quiet", "--bin", &bin_name];
+        args.extend(profile_args);
+
+        Command::new("cargo").args(&args).status()?;
+        Ok(())
+    }
+
+    /// Waits for a solution to exit, killing it if it exceeds one of the timeouts.
+    /// Progress is tracked through the records file: every record written marks the end of a phase.
+    /// Returns the time the current phase was running for if the solution was killed.
+    fn wait_with_timeouts(
+        cmd: &mut Child,
+        records_path: &Path,
+        timeouts: &Timeouts,
+    ) -> Result<Option<Duration>, Error> {
+        if !timeouts.is_set() {
+            cmd.wait()?;
+            return Ok(None);
+        }
+
+        let day_start = Instant::now();
+        let mut phase_start = day_start;
+        let mut records_seen = 0;
+
+        while cmd.try_wait()?.is_none() {
+            let records_written = fs::read_to_string(records_path)
+                .map(|s| s.lines().count())
+                .unwrap_or(0);
+
+            if records_written > records_seen {
+                records_seen = records_written;
+                phase_start = Instant::now();
+            }
+
+            let day_exceeded = timeouts.day.is_some_and(|t| day_start.elapsed() > t);
+            let part_exceeded = timeouts.part.is_some_and(|t| phase_start.elapsed() > t);
+
+            if day_exceeded || part_exceeded {
+                kill_tree(cmd.id());
+                cmd.wait()?;
+                return Ok(Some(phase_start.elapsed()));
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        Ok(None)
+    }
+
+    /// Kills a process and all of its descendants, e.g. `cargo` and the solution binary it started.
+    #[cfg(unix)]
+    fn kill_tree(pid: u32) {
+        let pid = pid.to_string();
+
+        // stop the parent first so that it cannot spawn further children.
+        let _ = Command::new("kill").args(["-STOP", &pid]).status();
+
+        if let Ok(output) = Command::new("pgrep").args(["-P", &pid]).output() {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|l| l.trim().parse().ok())
+                .for_each(kill_tree);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let _ = Command::new("kill").args(["-KILL", &pid]).status();
+    }
 
-        Ok(output)
+    /// Kills a process and all of its descendants, e.g. `cargo` and the solution binary it started.
+    #[cfg(windows)]
+    fn kill_tree(pid: u32) {
+        let _ = Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/T", "/F"])
+            .status();
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Creates records for the parts a killed solution did not finish and reports them.
+    fn timed_out_records(
+        records: &[Record],
+        day: Day,
+        elapsed: Duration,
+        report: &mut impl Write,
+    ) -> Vec<Record> {
+        // the line of the interrupted part was not terminated.
+        let _ = writeln!(report);
+
+        [1, 2]
+            .into_iter()
+            .filter(|part| !records.iter().any(|r| r.phase == Phase::Part(*part)))
+            .map(|part| {
+                let _ = writeln!(report, "Part {part}: ✖ timed out after {elapsed:.1?}");
+                Record {
+                    day,
+                    phase: Phase::Part(part),
+                    status: Status::TimedOut,
+                    answer: None,
+                    stats: Stats::single(elapsed),
+                    heap: None,
+                }
+            })
+            .collect()
+    }
+
+    fn get_records_path(name: impl Display) -> PathBuf {
+        env::temp_dir().join(format!("aoc-{}-{name}.jsonl", process::id()))
+    }
+
+    /// File that buffers the output of a solution, e.g. while other solutions are running in parallel.
+    pub fn get_output_path(day: Day) -> PathBuf {
+        env::temp_dir().join(format!("aoc-{}-{day}.out", process::id()))
+    }
+
+    fn open_output(path: &Path) -> Result<File, Error> {
+        Ok(OpenOptions::new().create(true).append(true).open(path)?)
+    }
+
+    /// Collects the timings of all solved phases.
+    pub fn collect_timing(records: &[Record], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            timed_out: false,
+            heap: None,
+            source_hash: None,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        for record in records {
+            if record.day != day {
+                continue;
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            if let Some(heap) = record.heap {
+                timings.heap = Some(timings.heap.unwrap_or_default().add(&heap));
+            }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            if record.status == Status::TimedOut {
+                timings.timed_out = true;
+            }
 
-                timings.total_nanos += nanos;
-            });
+            if record.status != Status::Solved {
+                continue;
+            }
 
-        timings
-    }
+            let timing = Some(PhaseTiming::from_stats(record.stats));
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+            match record.phase {
+                Phase::Parse => timings.parse = timing,
+                Phase::Part(1) => timings.part_1 = timing,
+                Phase::Part(2) => timings.part_2 = timing,
+                Phase::Part(_) => continue,
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            {
+                timings.total_nanos += record.stats.median.as_nanos() as f64;
+            }
+        }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        timings
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,47 +815,82 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use std::time::Duration;
+
+        use super::collect_timing;
+
+        use crate::{
+            day,
+            template::{
+                record::{Phase, Record, Status},
+                stats::Stats,
+                Day,
+            },
+        };
 
-        use crate::day;
+        fn record(day: Day, phase: Phase, answer: Option<&str>, nanos: u64) -> Record {
+            Record {
+                day,
+                phase,
+                status: if answer.is_some() || phase == Phase::Parse {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                answer: answer.map(String::from),
+                stats: Stats {
+                    samples: 100,
+                    ..Stats::single(Duration::from_nanos(nanos))
+                },
+                heap: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = collect_timing(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(day!(1), Phase::Part(1), Some("0"), 74),
+                    record(day!(1), Phase::Part(2), Some("10"), 74_130_000),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap().nanos, 74);
+            assert_eq!(res.part_2.unwrap().to_string(), "74.1ms");
+            assert_eq!(res.part_1.unwrap().samples, Some(100));
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answer() {
+            let res = collect_timing(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(
+                        day!(1),
+                        Phase::Part(1),
+                        Some("@ @ @ ( ) ms (2s @ 5 samples)"),
+                        2_000_000_000,
+                    ),
+                    record(
+                        day!(1),
+                        Phase::Part(2),
+                        Some("10s (100ms @ 1 samples)"),
+                        100_000_000,
+                    ),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap().to_string(), "2.0s");
+            assert_eq!(res.part_2.unwrap().to_string(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_missing_parts() {
+            let res = collect_timing(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    record(day!(1), Phase::Part(1), None, 10),
+                    record(day!(1), Phase::Part(2), None, 10),
                 ],
                 day!(1),
             );
@@ -253,5 +898,38 @@ pub mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn collects_parse_phase() {
+            let res = collect_timing(
+                &[
+                    record(day!(1), Phase::Parse, None, 2_000_000),
+                    record(day!(1), Phase::Part(1), Some("0"), 1_000_000),
+                    record(day!(1), Phase::Part(2), Some("10"), 3_000_000),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 6000000_f64);
+            assert_eq!(res.parse.unwrap().nanos, 2_000_000);
+            assert_eq!(res.parse.unwrap().samples, Some(100));
+            assert_eq!(res.part_1.unwrap().to_string(), "1.0ms");
+        }
+
+        #[test]
+        fn collects_timed_out_parts() {
+            let mut timed_out = record(day!(1), Phase::Part(2), None, 10);
+            timed_out.status = Status::TimedOut;
+
+            let res = collect_timing(
+                &[
+                    record(day!(1), Phase::Part(1), Some("0"), 1_000_000),
+                    timed_out,
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1000000_f64);
+            assert_eq!(res.timed_out, true);
+            assert_eq!(res.part_2.is_none(), true);
+        }
     }
 }
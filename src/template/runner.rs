@@ -2,64 +2,236 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::answers::{Answers, Verdict};
+use crate::template::readme_benchmarks::format_bytes;
+use crate::template::readme_stars;
+use crate::template::record::{Phase, Record, Status};
+use crate::template::stats::{HeapUsage, Stats};
+use crate::template::submissions::{Ledger, Outcome};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_client, Day, InputSource, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Configures how long and how often a solution part is benched.
+/// Read from the `AOC_BENCH_*` environment variables by the solution binaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate time budget for collecting samples.
+    pub duration: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl BenchConfig {
+    pub const DURATION_ENV: &'static str = "AOC_BENCH_TIME_MS";
+    pub const MIN_SAMPLES_ENV: &'static str = "AOC_BENCH_MIN_SAMPLES";
+    pub const MAX_SAMPLES_ENV: &'static str = "AOC_BENCH_MAX_SAMPLES";
+
+    /// Reads the bench configuration from the environment, falling back to the defaults for unset or invalid values.
+    pub fn from_env() -> Self {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            env::var(key).ok().and_then(|x| x.parse().ok())
+        }
+
+        let default = Self::default();
+
+        Self {
+            duration: var(Self::DURATION_ENV).map_or(default.duration, Duration::from_millis),
+            min_samples: var(Self::MIN_SAMPLES_ENV).unwrap_or(default.min_samples),
+            max_samples: var(Self::MAX_SAMPLES_ENV).unwrap_or(default.max_samples),
+        }
+    }
+
+    /// Environment variables that pass this configuration on to a solution binary.
+    pub fn envs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (Self::DURATION_ENV, self.duration.as_millis().to_string()),
+            (Self::MIN_SAMPLES_ENV, self.min_samples.to_string()),
+            (Self::MAX_SAMPLES_ENV, self.max_samples.to_string()),
+        ]
+    }
+
+    /// Number of bench iterations for a function that took `base_time` on its first run.
+    fn iterations(&self, base_time: &Duration) -> u128 {
+        let max_samples = cmp::max(self.max_samples, self.min_samples);
+        (self.duration.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+            .clamp(self.min_samples, max_samples)
+    }
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
+    let (result, stats, heap) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    // known answers only apply to the puzzle input.
+    let is_puzzle_input = InputSource::from_env().is_puzzle_input();
+
+    let verdict = result
+        .as_ref()
+        .filter(|_| is_puzzle_input)
+        .and_then(|result| Answers::read(year, day).check(part, &result.to_string()));
+
+    print_result(
+        &result,
+        &part_str,
+        &format!(
+            "{}{}",
+            format_verdict(verdict),
+            format_duration(&stats.median, stats.samples)
+        ),
+    );
+
+    if stats.samples > 1 {
+        println!("{}", format_stats(&stats));
+    }
+
+    if let Some(heap) = heap {
+        println!("{}", format_heap(&heap));
+    }
+
+    write_record(Record {
+        day,
+        phase: Phase::Part(part),
+        status: if result.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+        answer: result.as_ref().map(ToString::to_string),
+        stats,
+        heap,
+    });
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if is_puzzle_input {
+            accept_result(&result, year, day, part);
+            submit_result(result, year, day, part);
+        } else if env::args().any(|x| x == "--submit" || x == "--accept") {
+            eprintln!(
+                "Not submitting or accepting part {part}: the solution ran on a custom input."
+            );
+        }
     }
 }
 
+/// Run the parse step of a solution. It is timed the same way as a part, but there is no result to print or submit.
+pub fn run_parse<'a, P>(func: impl Fn(&'a str) -> P, input: &'a str, day: Day) -> P {
+    let label = "Parse";
+
+    let (parsed, stats, heap) = run_timed(func, input, |_| print!("{label}:"));
+
+    print!("\r");
+    println!("{label}:{}", format_duration(&stats.median, stats.samples));
+
+    if stats.samples > 1 {
+        println!("{}", format_stats(&stats));
+    }
+
+    if let Some(heap) = heap {
+        println!("{}", format_heap(&heap));
+    }
+
+    write_record(Record {
+        day,
+        phase: Phase::Parse,
+        status: Status::Solved,
+        answer: None,
+        stats,
+        heap,
+    });
+
+    parsed
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.
+///     Both bounds can be configured via [`BenchConfig`].)
+///
+/// When built with the `dhat-heap` feature, the heap usage of the first run is reported as well.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Stats, Option<HeapUsage>) {
     let timer = Instant::now();
-    let result = {
+    let (result, base_time, heap) = {
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let result = func(input);
+        let base_time = timer.elapsed();
+
+        (result, base_time, heap_usage())
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if std::env::args().any(|x| x == "--time") {
+        bench(func, input, &base_time, &BenchConfig::from_env())
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats, heap)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+#[cfg(feature = "dhat-heap")]
+#[allow(clippy::unnecessary_wraps)]
+fn heap_usage() -> Option<HeapUsage> {
+    let stats = dhat::HeapStats::get();
+    Some(HeapUsage {
+        total_bytes: stats.total_bytes,
+        max_bytes: stats.max_bytes as u64,
+        total_blocks: stats.total_blocks,
+    })
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+fn heap_usage() -> Option<HeapUsage> {
+    None
+}
+
+/// Benches a function after a short warm-up phase. The warm-up runs a tenth of the bench iterations and is not measured.
+pub fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> Stats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = config.iterations(base_time);
+    let warmup_iterations = cmp::max(bench_iterations / 10, 1);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input.clone())));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,19 +243,30 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
+    Stats::from_samples(&timers).unwrap_or_else(|| Stats::single(*base_time))
+}
+
+fn format_heap(heap: &HeapUsage) -> String {
+    format!(
+        "  ↳ heap: {} allocated in {} blocks, {} peak",
+        format_bytes(heap.total_bytes),
+        heap.total_blocks,
+        format_bytes(heap.max_bytes)
     )
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn write_record(record: Record) {
+    if let Err(e) = record.write() {
+        eprintln!("Failed to write result record: {e}");
+    }
+}
+
+fn format_verdict(verdict: Option<Verdict>) -> &'static str {
+    match verdict {
+        Some(Verdict::Correct) => " ✔",
+        Some(Verdict::Incorrect) => " ✘",
+        None => "",
+    }
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -94,6 +277,14 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
+/// Formats the spread of a benched part.
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "  ↳ min {:.1?}, median {:.1?}, mean {:.1?}, p95 {:.1?}, max {:.1?}, σ {:.1?} ({} outliers rejected)",
+        stats.min, stats.median, stats.mean, stats.p95, stats.max, stats.std_dev, stats.outliers
+    )
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -129,14 +320,46 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Parse the arguments passed to `solve` and store the result as the known answer if `--accept` matches this part.
+fn accept_result<T: Display>(result: &T, year: Year, day: Day, part: u8) {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(part_index) = args.iter().position(|x| x == "--accept") else {
+        return;
+    };
+
+    let Some(Ok(part_accept)) = args.get(part_index + 1).map(|x| x.parse::<u8>()) else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --accept 1");
+        process::exit(1);
+    };
+
+    if part_accept != part {
+        return;
+    }
+
+    let mut answers = Answers::read(year, day);
+    answers.set(part, result.to_string());
+
+    match answers.store(year, day) {
+        Ok(()) => println!("Stored answer of part {part} as accepted."),
+        Err(e) => eprintln!("Failed to store answer: {e}"),
+    }
+
+    if readme_stars::update(year).is_err() {
+        eprintln!("Failed to update star progress.");
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session token is available.
+///  3. the submission ledger does not already rule the answer out.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<String, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -159,11 +382,53 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let answer = result.to_string();
+    let mut ledger = Ledger::read(year, day);
+
+    if let Err(refusal) = ledger.check(part, &answer) {
+        eprintln!("Refusing to submit `{answer}`: {refusal}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let response = aoc_client::submit(year, day, part, &answer);
+
+    match &response {
+        Ok(response) => {
+            let outcome = Outcome::from_response(response);
+            record_submission(&mut ledger, year, day, part, &answer, outcome);
+        }
+        Err(e) => eprintln!("Failed to submit: {e}"),
+    }
+
+    Some(response)
+}
+
+/// Adds a submission to the ledger and stores correct answers as known answers.
+fn record_submission(
+    ledger: &mut Ledger,
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+    outcome: Outcome,
+) {
+    ledger.add(part, answer, outcome);
+
+    if let Err(e) = ledger.store(year, day) {
+        eprintln!("Failed to store submission: {e}");
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    if outcome == Outcome::Correct {
+        let mut answers = Answers::read(year, day);
+        answers.set(part, answer.to_string());
+
+        if let Err(e) = answers.store(year, day) {
+            eprintln!("Failed to store answer: {e}");
+        }
+
+        if readme_stars::update(year).is_err() {
+            eprintln!("Failed to update star progress.");
+        }
+    }
 }
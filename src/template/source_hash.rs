@@ -0,0 +1,51 @@
+/// Hashes the source of a solution, so timings can tell whether the code they measured has changed since.
+use std::fs;
+
+use crate::template::{readme_benchmarks::get_path_for_bin, Day, Year};
+
+/// Shared helpers are compiled into every solution, so they count towards its source.
+const LIB_PATH: &str = "./src/lib.rs";
+
+/// Hash of the solution of a day and `lib.rs`, or `None` if the solution can not be read.
+pub fn hash_solution(year: Year, day: Day) -> Option<String> {
+    let solution = fs::read(get_path_for_bin(year, day)).ok()?;
+    let lib = fs::read(LIB_PATH).unwrap_or_default();
+    Some(hash(&[&solution, &lib]))
+}
+
+/// 64-bit FNV-1a hash of `parts` as hex. Unlike `DefaultHasher`, it is stable across Rust versions.
+pub(crate) fn hash(parts: &[&[u8]]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+
+    for part in parts {
+        // NOTE: the length separates the parts, so moving code from one file to the other changes the hash.
+        for byte in part.len().to_le_bytes().iter().chain(part.iter()) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+
+    format!("{hash:016x}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::hash;
+
+    #[test]
+    fn hashes_are_stable() {
+        assert_eq!(hash(&[]), "cbf29ce484222325");
+        assert_eq!(hash(&[b"fn main() {}"]), hash(&[b"fn main() {}"]));
+    }
+
+    #[test]
+    fn hashes_change_with_sources() {
+        assert_ne!(hash(&[b"a", b"b"]), hash(&[b"a", b"c"]));
+        assert_ne!(hash(&[b"ab", b""]), hash(&[b"a", b"b"]));
+    }
+}
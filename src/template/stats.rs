@@ -0,0 +1,153 @@
+/// Summary statistics for a set of benchmark samples.
+use std::time::Duration;
+
+/// Multiplier of the inter-quartile range used for Tukey's fences.
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Describes the distribution of a benchmark run after outliers have been rejected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    /// Number of samples that were kept.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+}
+
+impl Stats {
+    /// Computes statistics for the given samples. Samples outside of Tukey's fences
+    /// (`1.5 * IQR` below the first or above the third quartile) are rejected before
+    /// any of the figures are calculated. Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let fence = (q3 - q1) * OUTLIER_FENCE;
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| *x >= q1 - fence && *x <= q3 + fence)
+            .collect();
+
+        let count = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / count;
+        let variance = kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        Some(Stats {
+            min: from_nanos(kept[0]),
+            median: from_nanos(percentile(&kept, 0.5)),
+            mean: from_nanos(mean),
+            p95: from_nanos(percentile(&kept, 0.95)),
+            max: from_nanos(kept[kept.len() - 1]),
+            std_dev: from_nanos(variance.sqrt()),
+            samples: kept.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+        })
+    }
+
+    /// Statistics for a single, un-benched run.
+    pub fn single(duration: Duration) -> Self {
+        Stats {
+            min: duration,
+            median: duration,
+            mean: duration,
+            p95: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+}
+
+/// Heap usage of a solution as reported by `dhat`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapUsage {
+    /// Bytes allocated over the whole run.
+    pub total_bytes: u64,
+    /// Largest amount of bytes allocated at the same time.
+    pub max_bytes: u64,
+    /// Number of allocations over the whole run.
+    pub total_blocks: u64,
+}
+
+impl HeapUsage {
+    /// Combines the usage of two consecutive runs.
+    #[must_use]
+    pub fn add(&self, other: &HeapUsage) -> Self {
+        HeapUsage {
+            total_bytes: self.total_bytes + other.total_bytes,
+            max_bytes: self.max_bytes.max(other.max_bytes),
+            total_blocks: self.total_blocks + other.total_blocks,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn computes_figures() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(14));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[100, 101, 99, 100, 102, 98, 10_000])).unwrap();
+        assert_eq!(stats.max, Duration::from_nanos(102));
+        assert_eq!(stats.mean, Duration::from_nanos(100));
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = Stats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats, Stats::single(Duration::from_nanos(42)));
+    }
+}
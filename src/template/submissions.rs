@@ -0,0 +1,349 @@
+/// Ledger of submitted answers, stored per day in `data/YYYY/submissions/NN.json`.
+/// Used to refuse submissions that are known to be wrong before they reach the server.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{create_data_dir, get_data_dir, Day, Year};
+
+/// The server's response to a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer was not judged, e.g. because of rate limiting.
+    Unknown,
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+/// All submissions of a single day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+}
+
+/// Reason for refusing a submission locally.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    AlreadyRejected,
+    AboveTooHigh(String),
+    BelowTooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Refusal::AlreadyRejected => write!(f, "this answer was already rejected."),
+            Refusal::AboveTooHigh(answer) => {
+                write!(f, "`{answer}` was already too high.")
+            }
+            Refusal::BelowTooLow(answer) => {
+                write!(f, "`{answer}` was already too low.")
+            }
+        }
+    }
+}
+
+impl Outcome {
+    /// Interprets the response text of a submission.
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("too high") {
+            Outcome::TooHigh
+        } else if response.contains("too low") {
+            Outcome::TooLow
+        } else if response.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn is_rejected(self) -> bool {
+        matches!(
+            self,
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl Ledger {
+    /// Reads the ledger of a day. If not present or invalid, returns an empty ledger.
+    pub fn read(year: Year, day: Day) -> Self {
+        fs::read_to_string(get_ledger_path(year, day))
+            .map_err(|x| x.to_string())
+            .and_then(Ledger::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Writes the ledger of a day, creating `data/YYYY/submissions` if necessary.
+    pub fn store(&self, year: Year, day: Day) -> Result<(), io::Error> {
+        create_data_dir(year)?;
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_ledger_path(year, day))?;
+        json.format_to(&mut file)
+    }
+
+    /// Records a submission, timestamped with the current time.
+    pub fn add(&mut self, part: u8, answer: &str, outcome: Outcome) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        self.submissions.push(Submission {
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at,
+        });
+    }
+
+    /// Checks whether an answer may be submitted, given the previous submissions of the part.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let submissions = self.submissions.iter().filter(|s| s.part == part);
+
+        for submission in submissions {
+            if submission.outcome == Outcome::Correct {
+                return Err(Refusal::AlreadySolved(submission.answer.clone()));
+            }
+
+            if submission.answer == answer && submission.outcome.is_rejected() {
+                return Err(Refusal::AlreadyRejected);
+            }
+
+            let (Ok(value), Ok(bound)) =
+                (answer.parse::<i128>(), submission.answer.parse::<i128>())
+            else {
+                continue;
+            };
+
+            if submission.outcome == Outcome::TooHigh && value >= bound {
+                return Err(Refusal::AboveTooHigh(submission.answer.clone()));
+            }
+
+            if submission.outcome == Outcome::TooLow && value <= bound {
+                return Err(Refusal::BelowTooLow(submission.answer.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn get_ledger_path(year: Year, day: Day) -> PathBuf {
+    get_data_dir(year)
+        .join("submissions")
+        .join(format!("{day}.json"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "too_high"),
+            Outcome::TooLow => write!(f, "too_low"),
+            Outcome::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "incorrect" => Ok(Outcome::Incorrect),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "unknown" => Ok(Outcome::Unknown),
+            s => Err(format!("unknown outcome `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Ledger> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Ledger) -> Self {
+        let submissions = value
+            .submissions
+            .iter()
+            .map(|s| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("part".into(), JsonValue::Number(f64::from(s.part)));
+                map.insert("answer".into(), JsonValue::String(s.answer.clone()));
+                map.insert("outcome".into(), JsonValue::String(s.outcome.to_string()));
+                map.insert(
+                    "submitted_at".into(),
+                    JsonValue::Number(s.submitted_at as f64),
+                );
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("submissions".into(), JsonValue::Array(submissions));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_submissions = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("submissions")
+            .ok_or("expected JSON document to have key `submissions`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.submissions` to be an array.")?;
+
+        Ok(Ledger {
+            submissions: json_submissions
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        Ok(Submission {
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            outcome: Outcome::from_str(string("outcome")?)?,
+            submitted_at: number("submitted_at")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Ledger, Outcome, Refusal};
+
+    fn get_mock_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.add(1, "100", Outcome::TooHigh);
+        ledger.add(1, "10", Outcome::TooLow);
+        ledger.add(1, "50", Outcome::Incorrect);
+        ledger.add(2, "abc", Outcome::Correct);
+        ledger
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(
+            Outcome::from_response("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::from_response("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::from_response("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::from_response("That's not the right answer. If you're stuck, ..."),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::from_response("You gave an answer too recently."),
+            Outcome::Unknown
+        );
+    }
+
+    #[test]
+    fn allows_answers_within_bounds() {
+        assert_eq!(get_mock_ledger().check(1, "42"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_rejected_answers() {
+        assert_eq!(
+            get_mock_ledger().check(1, "50"),
+            Err(Refusal::AlreadyRejected)
+        );
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(1, "150"),
+            Err(Refusal::AboveTooHigh("100".into()))
+        );
+        assert_eq!(ledger.check(1, "5"), Err(Refusal::BelowTooLow("10".into())));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        assert_eq!(
+            get_mock_ledger().check(2, "def"),
+            Err(Refusal::AlreadySolved("abc".into()))
+        );
+    }
+
+    #[test]
+    fn roundtrips_ledgers() {
+        let ledger = get_mock_ledger();
+        let json = JsonValue::from(&ledger).stringify().unwrap();
+        assert_eq!(Ledger::try_from(json).unwrap(), ledger);
+    }
+}
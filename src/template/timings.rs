@@ -1,17 +1,73 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap, fmt::Display, fs, io::Error, path::PathBuf, str::FromStr, time::Duration,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{
+    create_data_dir,
+    fingerprint::Fingerprint,
+    get_data_dir, source_hash,
+    stats::{HeapUsage, Stats},
+    Day, Year,
+};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Version of the `timings.json` schema. Files without a version are migrated when read.
+const TIMINGS_VERSION: u32 = 2;
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    /// Time spent in the `parse` hook, if the solution has one.
+    pub parse: Option<PhaseTiming>,
+    pub part_1: Option<PhaseTiming>,
+    pub part_2: Option<PhaseTiming>,
     pub total_nanos: f64,
+    /// Whether the solution was killed for exceeding a timeout.
+    pub timed_out: bool,
+    /// Heap usage of all phases combined, collected by `cargo time --memory`.
+    pub heap: Option<HeapUsage>,
+    /// Hash of the solution source when it was benched, `None` for timings stored before it was recorded.
+    pub source_hash: Option<String>,
+}
+
+impl Timing {
+    /// Whether the solution changed since it was benched, given the hash of its current source.
+    /// Timings without a hash are of unknown origin and always count as stale.
+    pub fn is_stale(&self, source_hash: Option<&str>) -> bool {
+        self.source_hash.is_none() || self.source_hash.as_deref() != source_hash
+    }
+}
+
+/// Benchmark time of a single phase of a day, i.e. its parse step or one of its parts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhaseTiming {
+    /// Median run time.
+    pub nanos: u64,
+    /// Number of samples the median was taken from. Unknown for timings migrated from the unversioned schema.
+    pub samples: Option<u128>,
+    /// Unknown for timings stored before stats were introduced.
+    pub stats: Option<Stats>,
+}
+
+impl PhaseTiming {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_stats(stats: Stats) -> Self {
+        PhaseTiming {
+            nanos: stats.median.as_nanos() as u64,
+            samples: Some(stats.samples),
+            stats: Some(stats),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+
+    /// The stats of this phase, or a single sample of its median if none were stored.
+    pub fn stats_or_median(&self) -> Stats {
+        self.stats.unwrap_or_else(|| Stats::single(self.duration()))
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -19,19 +75,22 @@ pub struct Timing {
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
     pub data: Vec<Timing>,
+    /// The environment the timings were measured in, `None` for timings stored before it was recorded.
+    pub fingerprint: Option<Fingerprint>,
 }
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        create_data_dir(year)?;
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_timings_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_timings_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,8 +110,17 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|a| a.day);
+        Timings {
+            data,
+            fingerprint: new.fingerprint.clone().or_else(|| self.fingerprint.clone()),
+        }
+    }
+
+    /// Whether `new` was measured in the same environment, i.e. whether the two can be merged without mixing machines.
+    /// Timings without a fingerprint are of unknown origin and never match, unless there are none.
+    pub fn matches_environment(&self, new: &Self) -> bool {
+        self.data.is_empty() || (self.fingerprint.is_some() && self.fingerprint == new.fingerprint)
     }
 
     /// Sum up total duration of timings as millis.
@@ -60,10 +128,48 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Whether both parts of a day were benched with its current source.
+    pub fn is_day_complete(&self, day: Day, source_hash: Option<&str>) -> bool {
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1.is_some() && t.part_2.is_some() && !t.is_stale(source_hash)
+        })
+    }
+
+    /// Days whose solution changed since they were benched.
+    pub fn stale_days(&self, year: Year) -> Vec<Day> {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .filter(|t| t.is_stale(source_hash::hash_solution(year, t.day).as_deref()))
+            .map(|t| t.day)
+            .collect()
+    }
+}
+
+fn get_timings_path(year: Year) -> PathBuf {
+    get_data_dir(year).join("timings.json")
+}
+
+/// Parses a duration as formatted by `{:.1?}`, e.g. `74.1ms`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    const UNITS: [(&str, f64); 5] = [
+        ("ns", 1.0),
+        ("µs", 1e3),
+        ("us", 1e3),
+        ("ms", 1e6),
+        ("s", 1e9),
+    ];
+
+    UNITS.iter().find_map(|(unit, factor)| {
+        let value: f64 = s.trim().strip_suffix(unit)?.parse().ok()?;
+        Some(Duration::from_secs_f64(value * factor / 1e9))
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Display for PhaseTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1?}", self.duration())
     }
 }
 
@@ -73,10 +179,21 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
+        map.insert(
+            "fingerprint".into(),
+            value
+                .fingerprint
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
 
         JsonValue::Object(map)
     }
@@ -85,12 +202,30 @@ impl From<Timings> for JsonValue {
 impl TryFrom<String> for Timings {
     type Error = String;
 
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files written before the schema was versioned have no version, they are migrated on the fly.
+        let version = match json.get("version") {
+            None => 1,
+            Some(v) => v
+                .get::<f64>()
+                .map(|v| *v as u32)
+                .ok_or("expected `json.version` to be a number.")?,
+        };
+
+        if version > TIMINGS_VERSION {
+            return Err(format!(
+                "timings were written with schema version {version}, expected at most {TIMINGS_VERSION}."
+            ));
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
@@ -99,8 +234,18 @@ impl TryFrom<String> for Timings {
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|timing| {
+                    if version == 1 {
+                        migrate_timing_v1(timing)
+                    } else {
+                        Timing::try_from(timing)
+                    }
+                })
                 .collect::<Result<_, _>>()?,
+            fingerprint: match json.get("fingerprint") {
+                None | Some(JsonValue::Null) => None,
+                Some(v) => Some(Fingerprint::try_from(v)?),
+            },
         })
     }
 }
@@ -111,27 +256,25 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        let phase = |p: Option<PhaseTiming>| p.map_or(JsonValue::Null, JsonValue::from);
+
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
+        map.insert("timed_out".into(), JsonValue::Boolean(value.timed_out));
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "heap".into(),
+            value.heap.map_or(JsonValue::Null, JsonValue::from),
         );
-
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "source_hash".into(),
+            value
+                .source_hash
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
         );
+        map.insert("parse".into(), phase(value.parse));
+        map.insert("part_1".into(), phase(value.part_1));
+        map.insert("part_2".into(), phase(value.part_2));
 
         JsonValue::Object(map)
     }
@@ -145,84 +288,391 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
+        let phase = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => PhaseTiming::try_from(v).map(Some),
+        };
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        Ok(Timing {
+            parse: phase("parse")?,
+            part_1: phase("part_1")?,
+            part_2: phase("part_2")?,
+            ..read_common_fields(json)?
+        })
+    }
+}
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+/// Reads a timing of the unversioned schema, which stored parts as display strings like `"74.1ms"`
+/// and their stats (if any) under separate `*_stats` keys.
+fn migrate_timing_v1(value: &JsonValue) -> Result<Timing, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing to be a JSON object.")?;
+
+    let phase = |key: &str| -> Result<Option<PhaseTiming>, String> {
+        // NOTE: stats are optional, timings stored before they were introduced do not contain them.
+        if let Some(v) = json.get(&format!("{key}_stats")).filter(|v| !v.is_null()) {
+            return Ok(Some(PhaseTiming::from_stats(Stats::try_from(v)?)));
+        }
 
-        let total_nanos = json
-            .get("total_nanos")
+        let Some(display) = json.get(key).and_then(|v| v.get::<String>()) else {
+            return Ok(None);
+        };
+
+        let duration = parse_duration(display).ok_or(format!(
+            "Expected timing.{key} to be a duration, got `{display}`."
+        ))?;
+
+        Ok(Some(PhaseTiming {
+            nanos: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+            samples: None,
+            stats: None,
+        }))
+    };
+
+    for key in ["part_1", "part_2"] {
+        json.get(key)
+            .filter(|v| v.is_null() || v.get::<String>().is_some())
+            .ok_or(format!("Expected timing.{key} to be null or string."))?;
+    }
+
+    Ok(Timing {
+        // NOTE: optional, timings stored before parse hooks were introduced do not contain it.
+        parse: phase("parse")?,
+        part_1: phase("part_1")?,
+        part_2: phase("part_2")?,
+        ..read_common_fields(json)?
+    })
+}
+
+/// Reads the fields that are the same in all schema versions.
+fn read_common_fields(json: &HashMap<String, JsonValue>) -> Result<Timing, String> {
+    let day = json
+        .get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.")?;
+
+    let total_nanos = json
+        .get("total_nanos")
+        .and_then(|v| v.get::<f64>().copied())
+        .ok_or("Expected timing.total_nanos to be a number.")?;
+
+    let timed_out = json
+        .get("timed_out")
+        .and_then(|v| v.get::<bool>().copied())
+        .unwrap_or(false);
+
+    let heap = match json.get("heap") {
+        None | Some(JsonValue::Null) => None,
+        Some(v) => Some(HeapUsage::try_from(v)?),
+    };
+
+    let source_hash = json
+        .get("source_hash")
+        .and_then(|v| v.get::<String>())
+        .cloned();
+
+    Ok(Timing {
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos,
+        timed_out,
+        heap,
+        source_hash,
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<PhaseTiming> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: PhaseTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos as f64));
+        map.insert(
+            "samples".into(),
+            value
+                .samples
+                .map_or(JsonValue::Null, |s| JsonValue::Number(s as f64)),
+        );
+        map.insert(
+            "stats".into(),
+            value.stats.map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PhaseTiming {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected phase timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
             .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+            .ok_or("Expected phase.nanos to be a number.")?;
 
-        Ok(Timing {
-            day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|v| v as u128);
+
+        let stats = match json.get("stats") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(Stats::try_from(v)?),
+        };
+
+        Ok(PhaseTiming {
+            nanos: nanos as u64,
+            samples,
+            stats,
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+impl From<Stats> for JsonValue {
+    fn from(value: Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("min".into(), nanos_to_json(value.min));
+        map.insert("median".into(), nanos_to_json(value.median));
+        map.insert("mean".into(), nanos_to_json(value.mean));
+        map.insert("p95".into(), nanos_to_json(value.p95));
+        map.insert("max".into(), nanos_to_json(value.max));
+        map.insert("std_dev".into(), nanos_to_json(value.std_dev));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(Stats {
+            min: Duration::from_nanos(number("min")? as u64),
+            median: Duration::from_nanos(number("median")? as u64),
+            mean: Duration::from_nanos(number("mean")? as u64),
+            p95: Duration::from_nanos(number("p95")? as u64),
+            max: Duration::from_nanos(number("max")? as u64),
+            std_dev: Duration::from_nanos(number("std_dev")? as u64),
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<HeapUsage> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: HeapUsage) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+        map.insert(
+            "max_bytes".into(),
+            JsonValue::Number(value.max_bytes as f64),
+        );
+        map.insert(
+            "total_blocks".into(),
+            JsonValue::Number(value.total_blocks as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HeapUsage {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected heap to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|v| v as u64)
+                .ok_or(format!("Expected heap.{key} to be a number."))
+        };
+
+        Ok(HeapUsage {
+            total_bytes: number("total_bytes")?,
+            max_bytes: number("max_bytes")?,
+            total_blocks: number("total_blocks")?,
+        })
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos_to_json(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{PhaseTiming, Timing, Timings};
+
+    fn phase(millis: u64) -> PhaseTiming {
+        PhaseTiming {
+            nanos: millis * 1_000_000,
+            samples: Some(10),
+            stats: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: Some(phase(10)),
+                    part_2: Some(phase(20)),
                     total_nanos: 3e+10,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(phase(30)),
+                    part_2: Some(phase(40)),
                     total_nanos: 7e+10,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(phase(40)),
                     part_2: None,
                     total_nanos: 4e+10,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 },
             ],
+            fingerprint: None,
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use tinyjson::JsonValue;
+
+        use super::get_mock_timings;
+        use crate::{
+            day,
+            template::{
+                stats::Stats,
+                timings::{parse_duration, Timings},
+            },
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10, "stats": null }, "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1.unwrap().nanos, 1_000_000);
+            assert_eq!(timing.part_1.unwrap().samples, Some(10));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn roundtrips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+
+        #[test]
+        fn migrates_unversioned_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.5ms", "part_2": null, "total_nanos": 1500000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1.unwrap().nanos, 1_500_000);
+            assert_eq!(timing.part_1.unwrap().samples, None);
+            assert_eq!(timing.part_2, None);
+        }
+
+        #[test]
+        fn migrates_unversioned_timings_with_stats() {
+            let stats = Stats {
+                samples: 42,
+                ..Stats::single(Duration::from_micros(8))
+            };
+            let json = format!(
+                r#"{{ "data": [{{ "day": "06", "parse": "8.0µs", "parse_stats": {}, "part_1": null, "part_2": null, "total_nanos": 8000 }}] }}"#,
+                JsonValue::from(stats).stringify().unwrap()
+            );
+            let timings = Timings::try_from(json).unwrap();
+            let parse = timings.data.first().unwrap().parse.unwrap();
+            assert_eq!(parse.nanos, 8000);
+            assert_eq!(parse.samples, Some(42));
+            assert_eq!(parse.stats, Some(stats));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_newer_versions() {
+            let json = r#"{ "version": 99, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn parses_durations() {
+            assert_eq!(parse_duration("74.0ns"), Some(Duration::from_nanos(74)));
+            assert_eq!(parse_duration("8.7µs"), Some(Duration::from_nanos(8700)));
+            assert_eq!(parse_duration("74.1ms"), Some(Duration::from_micros(74100)));
+            assert_eq!(parse_duration("2.0s"), Some(Duration::from_secs(2)));
+            assert_eq!(parse_duration("fast"), None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -269,6 +719,7 @@ mod tests {
     }
 
     mod is_day_complete {
+        use super::phase;
         use crate::{
             day,
             template::timings::{Timing, Timings},
@@ -279,13 +730,57 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse: None,
+                    part_1: Some(phase(1)),
+                    part_2: Some(phase(2)),
+                    total_nanos: 3_000_000_000_f64,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: Some("abc".into()),
+                }],
+                fingerprint: None,
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1), Some("abc")), true);
+        }
+
+        #[test]
+        fn handles_changed_sources() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(phase(1)),
+                    part_2: Some(phase(2)),
+                    total_nanos: 3_000_000_000_f64,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: Some("abc".into()),
+                }],
+                fingerprint: None,
+            };
+
+            assert_eq!(timings.is_day_complete(day!(1), Some("def")), false);
+            assert_eq!(timings.is_day_complete(day!(1), None), false);
+        }
+
+        #[test]
+        fn handles_unknown_sources() {
+            let timings = Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(phase(1)),
+                    part_2: Some(phase(2)),
                     total_nanos: 3_000_000_000_f64,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 }],
+                fingerprint: None,
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1), Some("abc")), false);
         }
 
         #[test]
@@ -293,13 +788,18 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: Some(phase(1)),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 }],
+                fingerprint: None,
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1), Some("abc")), false);
         }
 
         #[test]
@@ -307,20 +807,28 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 }],
+                fingerprint: None,
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1), Some("abc")), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                fingerprint::Fingerprint,
+                timings::{Timing, Timings},
+            },
         };
 
         use super::get_mock_timings;
@@ -331,10 +839,15 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 }],
+                fingerprint: None,
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -351,10 +864,15 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    timed_out: false,
+                    heap: None,
+                    source_hash: None,
                 }],
+                fingerprint: None,
             };
             let merged = timings.merge(&other);
 
@@ -380,5 +898,49 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        fn fingerprint(cpu: &str) -> Option<Fingerprint> {
+            Some(Fingerprint {
+                cpu: Some(cpu.into()),
+                cores: Some(8),
+                os: "linux x86_64".into(),
+                rustc: None,
+                profile: "release".into(),
+            })
+        }
+
+        #[test]
+        fn keeps_the_newest_fingerprint() {
+            let timings = Timings {
+                fingerprint: fingerprint("a"),
+                ..get_mock_timings()
+            };
+            let other = Timings {
+                fingerprint: fingerprint("b"),
+                ..Timings::default()
+            };
+            assert_eq!(timings.merge(&other).fingerprint, fingerprint("b"));
+            assert_eq!(
+                timings.merge(&Timings::default()).fingerprint,
+                fingerprint("a")
+            );
+        }
+
+        #[test]
+        fn matches_environments() {
+            let new = Timings {
+                fingerprint: fingerprint("a"),
+                ..Timings::default()
+            };
+            let stored = |cpu: Option<&str>| Timings {
+                fingerprint: cpu.and_then(fingerprint),
+                ..get_mock_timings()
+            };
+
+            assert!(stored(Some("a")).matches_environment(&new));
+            assert!(!stored(Some("b")).matches_environment(&new));
+            assert!(!stored(None).matches_environment(&new));
+            assert!(Timings::default().matches_environment(&new));
+        }
     }
 }
@@ -0,0 +1,191 @@
+/// Benches alternative implementations of the same part against each other, e.g. a naive and an optimized `part_two`.
+/// Variants are registered with `solution!(2024, 17, variants = [2 => part_two_search])` and compared with `cargo solve 17 --compare`.
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io::{stdout, Write};
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+use crate::template::runner::{bench, BenchConfig};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+/// Passed to a solution binary to bench its variants instead of solving.
+pub const COMPARE_ARG: &str = "--compare";
+
+/// An implementation of a part. The answer is type-erased, so variants of both parts can be listed together.
+pub struct Variant<'a, I> {
+    pub name: &'static str,
+    pub part: u8,
+    answer: Box<dyn Fn(I) -> Option<String> + 'a>,
+    run: Box<dyn Fn(I) + 'a>,
+}
+
+impl<'a, I: 'a> Variant<'a, I> {
+    pub fn new<T: Display + 'a>(
+        name: &'static str,
+        part: u8,
+        func: impl Fn(I) -> Option<T> + Copy + 'a,
+    ) -> Self {
+        Variant {
+            name,
+            part,
+            answer: Box::new(move |input| func(input).map(|result| result.to_string())),
+            // the benched function skips formatting the answer, so it is timed like `run_part` does.
+            run: Box::new(move |input| {
+                black_box(func(input));
+            }),
+        }
+    }
+}
+
+/// Outcome of benching a single variant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariantResult {
+    pub name: &'static str,
+    pub answer: Option<String>,
+    pub median: Duration,
+}
+
+/// Whether the solution binary was asked to compare its variants.
+pub fn is_compare() -> bool {
+    env::args().any(|x| x == COMPARE_ARG)
+}
+
+/// Benches every variant of each part on the same input and prints a speedup table per part.
+/// Exits with a non-zero status if the variants of a part disagree on the answer.
+pub fn compare<I: Clone>(input: I, variants: &[Variant<I>]) {
+    let config = BenchConfig::from_env();
+    let mut disagreements = 0;
+
+    for part in [1, 2] {
+        let results: Vec<VariantResult> = variants
+            .iter()
+            .filter(|v| v.part == part)
+            .map(|variant| run_variant(variant, input.clone(), &config))
+            .collect();
+
+        if results.is_empty() {
+            continue;
+        }
+
+        println!("\n{ANSI_BOLD}Part {part}{ANSI_RESET}");
+        for line in format_table(&results) {
+            println!("{line}");
+        }
+
+        if !agree(&results) {
+            disagreements += 1;
+            eprintln!("The variants of part {part} do not agree on the answer.");
+        }
+    }
+
+    if disagreements > 0 {
+        process::exit(1);
+    }
+}
+
+fn run_variant<I: Clone>(variant: &Variant<I>, input: I, config: &BenchConfig) -> VariantResult {
+    print!("{}:", variant.name);
+    let _ = stdout().flush();
+
+    let timer = Instant::now();
+    let answer = (variant.answer)(input.clone());
+    let base_time = timer.elapsed();
+
+    let stats = bench(&*variant.run, input, &base_time, config);
+    // clears the progress line, the results are printed as a table.
+    print!("\r{}\r", " ".repeat(variant.name.len() + 12));
+
+    VariantResult {
+        name: variant.name,
+        answer,
+        median: stats.median,
+    }
+}
+
+/// All variants of a part found the same answer.
+fn agree(results: &[VariantResult]) -> bool {
+    results.windows(2).all(|w| w[0].answer == w[1].answer)
+}
+
+/// Speedup of a variant relative to the first one, which is the part registered without a variant name.
+fn speedup(baseline: Duration, median: Duration) -> f64 {
+    if median.is_zero() {
+        return f64::INFINITY;
+    }
+    baseline.as_secs_f64() / median.as_secs_f64()
+}
+
+fn format_table(results: &[VariantResult]) -> Vec<String> {
+    let Some(baseline) = results.first() else {
+        return vec![];
+    };
+
+    let width = results.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    let consensus = baseline.answer.as_deref();
+
+    results
+        .iter()
+        .map(|result| {
+            let verdict = if result.answer.as_deref() == consensus {
+                "✔"
+            } else {
+                "✘"
+            };
+            format!(
+                "  {verdict} {:<width$}  {:>10}  {:>8.2}x  {}",
+                result.name,
+                format!("{:.1?}", result.median),
+                speedup(baseline.median, result.median),
+                result.answer.as_deref().unwrap_or("✖")
+            )
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{agree, format_table, Variant, VariantResult};
+
+    fn result(name: &'static str, answer: &str, micros: u64) -> VariantResult {
+        VariantResult {
+            name,
+            answer: Some(answer.into()),
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn erases_answers() {
+        let variant = Variant::new("part_one", 1, |input: &str| Some(input.len()));
+        assert_eq!((variant.answer)("abc"), Some("3".into()));
+    }
+
+    #[test]
+    fn checks_agreement() {
+        assert!(agree(&[result("a", "42", 10), result("b", "42", 1)]));
+        assert!(!agree(&[result("a", "42", 10), result("b", "41", 1)]));
+    }
+
+    #[test]
+    fn formats_speedups() {
+        let table = format_table(&[
+            result("part_two", "42", 1000),
+            result("part_two_search", "42", 10),
+            result("part_two_wrong", "7", 2000),
+        ]);
+
+        assert_eq!(
+            table,
+            vec![
+                "  ✔ part_two              1.0ms      1.00x  42",
+                "  ✔ part_two_search      10.0µs    100.00x  42",
+                "  ✘ part_two_wrong        2.0ms      0.50x  7",
+            ]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{AllDays, Day};
+
+/// The year of the first event.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first event that is shortened to twelve days.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A year in which an event took place (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The environment variable that selects the year if `--year` is not passed.
+    pub const ENV: &'static str = "AOC_YEAR";
+
+    /// Creates a [`Year`] from the provided value if an event took place in it,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the year from `AOC_YEAR`, which is set in `.cargo/config.toml`.
+    pub fn from_env() -> Option<Self> {
+        env::var(Self::ENV).ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The last day of the event. Events before 2025 have 25 days, later ones 12.
+    pub const fn last_day(self) -> u8 {
+        if self.0 >= FIRST_SHORT_YEAR {
+            12
+        } else {
+            25
+        }
+    }
+
+    /// Whether the event of this year has a puzzle on the given day.
+    pub fn has_day(self, day: Day) -> bool {
+        day.into_inner() <= self.last_day()
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::until(self.last_day())
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value of 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::{day, year};
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().unwrap(), year!(2024));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn shortens_recent_events() {
+        assert_eq!(year!(2024).days().count(), 25);
+        assert_eq!(year!(2025).days().count(), 12);
+        assert_eq!(year!(2025).days().last(), Some(day!(12)));
+        assert!(year!(2024).has_day(day!(25)));
+        assert!(!year!(2025).has_day(day!(13)));
+    }
+}